@@ -0,0 +1,77 @@
+#![no_std]
+
+//! Verifies that flagnum and its generated code build without the standard library.
+//!
+//! Build with `cargo build -p flagnum-no-std-check` to check without any optional
+//! flagnum features, optionally adding the `alloc` and `serde` features. The flagnum
+//! tests themselves can be run without the standard library features with
+//! `cargo test -p flagnum --no-default-features`.
+
+use flagnum::Flags;
+
+#[flagnum::flag(
+    pub Weekdays,
+    groups(pub WEEKEND, pub WORKDAYS = !WEEKEND, exclusive FIRST = Monday | Tuesday),
+    group_enum(WeekdayGroup, WeekdayGroups),
+    partition(WEEKEND, WORKDAYS),
+    group_methods,
+    item_accessors,
+    item_const_fns,
+)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    #[groups(WEEKEND)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[flagnum::flag(pub Codes, keep_discriminants, retain_unknown_bits)]
+#[repr(u16)]
+pub enum Code {
+    Ok = 200,
+    NotFound = 404,
+}
+
+#[flagnum::flag(pub LargeSet)]
+pub enum Large {
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15,
+    B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11, B12, B13, B14, B15,
+    C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12, C13, C14, C15,
+    D0, D1, D2, D3, D4, D5, D6, D7, D8, D9, D10, D11, D12, D13, D14, D15,
+    E0, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14, E15,
+    F0, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15,
+    G0, G1, G2, G3, G4, G5, G6, G7, G8, G9, G10, G11, G12, G13, G14, G15,
+    H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15,
+    Last,
+}
+
+pub const WEEKEND_DAYS: usize = Weekdays::WEEKEND.len();
+
+pub const SATURDAY_INDEX: usize = Weekday::Saturday.index();
+
+const _: () = assert!(Weekdays::FULL.const_contains(Weekdays::WORKDAYS));
+
+pub fn workdays_left(days: Weekdays) -> usize {
+    (days & Weekdays::WORKDAYS).into_iter().rev().count()
+}
+
+pub fn last_of(set: LargeSet) -> Option<Large> {
+    set.into_iter().last()
+}
+
+pub fn write_days(days: Weekdays, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{days} {days:?} {days:#x} {}", days.display_grouped())
+}
+
+pub fn parse_days(text: &str) -> Result<Weekdays, flagnum::ParseSetError> {
+    text.parse()
+}
+
+pub fn parse_day(name: &str) -> Result<Weekday, flagnum::ParseItemError> {
+    name.parse()
+}
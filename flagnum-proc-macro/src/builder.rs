@@ -1,16 +1,29 @@
-use proc_macro2::{TokenStream, Literal};
-use quote::quote;
+use std::collections::{BTreeSet, HashMap};
+
+use proc_macro2::{TokenStream, Literal, Span};
+use quote::{quote, quote_spanned, format_ident, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Error, Ident, parse_str, Visibility, Variant, Lit, ExprLit, Expr};
+use syn::ext::IdentExt;
+use syn::{Attribute, BinOp, Error, ExprBinary, ExprGroup, ExprParen, ExprPath, ExprUnary, UnOp, Ident, Path, parse_str, parse_quote, Visibility, Variant, Lit, ExprLit, Expr, LitInt, LitStr};
 use syn::token::{Eq};
 
-use crate::parser::{FlagnumEnum, FlagnumDecl, WithAttrs};
+use crate::parser::{
+    ConstraintKind, FlagnumEnum, FlagnumDecl, GroupConstraint, GroupEnumDecl, ItemAccessorsDecl,
+    WithAttrs,
+};
 
 
 pub struct FlagnumContext {
     decl: FlagnumDecl,
     body: FlagnumEnum,
-    repr_type: Ident,
+    repr: FlagnumRepr,
+    repr_type: TokenStream,
+    krate: Path,
+    positions: Vec<usize>,
+    group_positions: Vec<Vec<usize>>,
+    names: Vec<String>,
+    separator: String,
+    item_accessors: Option<RenameRule>,
     item_type: Ident,
     set_type: Ident,
     vis: Visibility,
@@ -18,28 +31,111 @@ pub struct FlagnumContext {
 
 impl FlagnumContext {
     pub fn new(decl: FlagnumDecl, body: FlagnumEnum) -> syn::Result<Self> {
-        let Some(repr) = FlagnumRepr::try_from_enum_len(body.item_enum.variants.len()) else {
-            return Err(Error::new(
-                body.item_enum.enum_token.span,
-                "flagnum only supports enums with up to 128 variants",
-            ));
+        if !decl.keep_discriminants {
+            for variant in &body.item_enum.variants {
+                if let Some((eq, _)) = variant.discriminant {
+                    return Err(Error::new(
+                        eq.spans[0],
+                        "flagnum enums cannot have explicit discriminant values \
+                        unless `keep_discriminants` is declared",
+                    ));
+                }
+            }
+        }
+        let positions = variant_positions(&body)?;
+        let krate = decl.krate.clone().unwrap_or_else(|| parse_quote! { ::flagnum });
+        let repr = match &decl.repr {
+            Some(ident) => FlagnumRepr::try_from_ident(ident)?,
+            None => {
+                let len = positions.iter().map(|position| position + 1).max();
+                FlagnumRepr::from_bit_len(len, &krate)
+            },
         };
         for group in body.grouped.keys() {
-            if !decl.groups.iter().any(|decl_group| decl_group.value == *group) {
+            match decl.groups.iter().find(|decl_group| decl_group.value.name == *group) {
+                None => {
+                    return Err(Error::new(
+                        group.span(),
+                        format!("Undeclared flagnum group `{group}`"),
+                    ));
+                },
+                Some(decl_group) if decl_group.value.expr.is_some() => {
+                    return Err(Error::new(
+                        group.span(),
+                        format!(
+                            "Group `{group}` is defined by an expression and cannot be \
+                            assigned to variants",
+                        ),
+                    ));
+                },
+                Some(_) => (),
+            }
+        }
+        let group_positions = evaluate_groups(&decl, &body, &positions)?;
+        let repr_type = repr.to_type();
+        let rename_rule = match &decl.rename_all {
+            Some(lit) => RenameRule::try_from_lit(lit)?,
+            None => RenameRule::None,
+        };
+        let names = variant_names(&body, &rename_rule)?;
+        let item_accessors = match &decl.item_accessors {
+            Some(ItemAccessorsDecl { case: Some(lit), .. }) => {
+                Some(RenameRule::try_from_accessor_lit(lit)?)
+            },
+            Some(ItemAccessorsDecl { case: None, .. }) => Some(RenameRule::SnakeCase),
+            None => None,
+        };
+        if item_accessors.is_some() {
+            check_item_constants(&decl, &body)?;
+        }
+        check_set_methods(&decl, &body, item_accessors.as_ref())?;
+        check_group_enum_variants(&decl)?;
+        let separator = match &decl.separator {
+            Some(lit) => {
+                let separator = lit.value();
+                let is_ambiguous = names.iter()
+                    .cloned()
+                    .chain(decl.groups.iter().map(|group| group.value.name.unraw().to_string()))
+                    .any(|name| name.contains(&separator));
+                if separator.is_empty() || is_ambiguous {
+                    return Err(Error::new(
+                        lit.span(),
+                        "flagnum separators must be non-empty and not occur in item or group names",
+                    ));
+                }
+                separator
+            },
+            None => "|".into(),
+        };
+        let variants = body.item_enum.variants.iter().zip(&body.bits);
+        for ((variant, bit), &position) in variants.zip(&positions) {
+            if position >= repr.capacity() {
                 return Err(Error::new(
-                    group.span(),
-                    format!("Undeclared flagnum group `{group}`"),
+                    bit.as_ref().map_or_else(|| variant.ident.span(), LitInt::span),
+                    format!(
+                        "Variant `{}` does not fit into the `{}` flagnum representation",
+                        variant.ident,
+                        repr.name(),
+                    ),
                 ));
             }
         }
-        let repr_type = repr.to_ident();
+        check_skipped_derives(&decl.skip_item_derives, ITEM_DERIVES, "item")?;
+        check_skipped_derives(&decl.skip_set_derives, SET_DERIVES, "set")?;
         let item_type = body.item_enum.ident.clone();
         let set_type = decl.set.value.clone();
         let vis = body.item_enum.vis.clone();
         Ok(Self {
             decl,
             body,
+            repr,
             repr_type,
+            krate,
+            positions,
+            group_positions,
+            names,
+            separator,
+            item_accessors,
             item_type,
             set_type,
             vis,
@@ -51,58 +147,418 @@ impl FlagnumContext {
         (variants.len(), variants.iter().map(|variant| &variant.ident))
     }
 
+    /// The bits of `items` belonging to known items.
+    ///
+    /// Only sets retaining unknown bits need to mask them out.
+    fn known_bits(&self, items: TokenStream) -> TokenStream {
+        if self.decl.retain_unknown_bits {
+            let krate = &self.krate;
+            self.repr.intersection(items, quote! { <Self as #krate::Flags>::FULL.items })
+        } else {
+            items
+        }
+    }
+
+    /// The path to an item of the flagnum crate, for use in documentation links.
+    fn doc_path(&self, name: &str) -> String {
+        let krate = self.krate.to_token_stream().to_string().replace(' ', "");
+        format!("{krate}::{name}")
+    }
+
+    /// Whether the item type gets the given trait implemented automatically.
+    fn item_derives(&self, name: &str) -> bool {
+        !self.decl.skip_item_derives.iter().any(|skipped| skipped == name)
+    }
+
+    /// Whether the set type gets the given trait implemented automatically.
+    fn set_derives(&self, name: &str) -> bool {
+        !self.decl.skip_set_derives.iter().any(|skipped| skipped == name)
+    }
+
+    /// Bounds on the skipped required traits, for the `where` clauses of generated impls.
+    ///
+    /// The bounds are higher-ranked, so instead of failing to compile, the impls simply
+    /// don't apply when a skipped trait isn't implemented manually. This leaves the
+    /// required impl checks as the only reported errors.
+    fn skipped_derive_bounds(&self) -> TokenStream {
+        let Self { item_type, set_type, .. } = self;
+        let item_bounds = required_skipped(&self.decl.skip_item_derives).map(|name| {
+            let path = required_trait_path(&name.to_string(), Span::call_site());
+            quote! { for<'__flagnum> #item_type: #path, }
+        });
+        let set_bounds = required_skipped(&self.decl.skip_set_derives).map(|name| {
+            let path = required_trait_path(&name.to_string(), Span::call_site());
+            quote! { for<'__flagnum> #set_type: #path, }
+        });
+        quote! { #(#item_bounds)* #(#set_bounds)* }
+    }
+
+    /// Split the given required traits into the ones to derive and impls of the others.
+    ///
+    /// Derives relying on a supertrait in `skipped` fail to compile when the supertrait isn't
+    /// implemented manually, so those traits are implemented with the
+    /// [`skipped_derive_where`](Self::skipped_derive_where) clause instead. The `key`
+    /// maps a reference to a value to the key it is ordered by, matching the derives.
+    fn build_required_impls<'a>(
+        &self,
+        ty: &Ident,
+        skipped: &[Ident],
+        names: impl Iterator<Item = &'a &'static str>,
+        key: impl Fn(TokenStream) -> TokenStream,
+    ) -> (Vec<TokenStream>, TokenStream) {
+        let skipped_where = self.skipped_derive_where();
+        let mut derives = Vec::new();
+        let mut impls = TokenStream::new();
+        for &name in names {
+            let path = required_trait_path(name, Span::call_site());
+            let relies_on_skipped = required_supertraits(name)
+                .iter()
+                .any(|supertrait| skipped.iter().any(|skipped| skipped == supertrait));
+            if !relies_on_skipped {
+                derives.push(path);
+                continue;
+            }
+            let (key_self, key_other) = (key(quote! { self }), key(quote! { other }));
+            impls.extend(match name {
+                "PartialOrd" => quote! {
+                    impl #path for #ty #skipped_where {
+                        fn partial_cmp(
+                            &self,
+                            other: &Self,
+                        ) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::cmp::PartialOrd::partial_cmp(&#key_self, &#key_other)
+                        }
+                    }
+                },
+                "Ord" => quote! {
+                    impl #path for #ty #skipped_where {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ord::cmp(&#key_self, &#key_other)
+                        }
+                    }
+                },
+                _ => quote! {
+                    impl #path for #ty #skipped_where {}
+                },
+            });
+        }
+        (derives, impls)
+    }
+
+    /// A `where` clause with the [`skipped_derive_bounds`](Self::skipped_derive_bounds),
+    /// if there are any.
+    fn skipped_derive_where(&self) -> Option<TokenStream> {
+        let bounds = self.skipped_derive_bounds();
+        (!bounds.is_empty()).then(|| quote! { where #bounds })
+    }
+
     pub fn build(self) -> TokenStream {
         let item = self.build_item_type();
         let set = self.build_set_type();
+        let group_enum = self.build_group_enum();
         quote! {
             #item
             #set
+            #group_enum
         }
     }
 
+    /// Build the group enum with its own set type, and the methods relating them to
+    /// the item and set types.
+    ///
+    /// The group enum is a regular flagnum enum, so it is generated by expanding
+    /// another `flagnum::flag` attribute.
+    fn build_group_enum(&self) -> Option<TokenStream> {
+        let skipped_where = self.skipped_derive_where();
+        let Self { vis, item_type, set_type, krate, .. } = self;
+        let GroupEnumDecl { item: group_item, set: group_set } = self.decl.group_enum.as_ref()?;
+        let groups: Vec<_> = self.decl.groups.iter().filter(|group| group.vis.is_some()).collect();
+        let names: Vec<_> = groups.iter().map(|group| &group.value.name).collect();
+        let variants: Vec<_> = names.iter()
+            .map(|name| Ident::new(&upper_camel_case(&name.unraw().to_string()), name.span()))
+            .collect();
+        let variant_attrs = groups.iter().map(|group| {
+            let attrs = group.attrs.iter();
+            let attrs = attrs.filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("cfg"));
+            let name = group.value.name.unraw().to_string();
+            quote! { #(#attrs)* #[flagnum(name = #name)] }
+        });
+        let cfgs: Vec<Vec<_>> = groups.iter().map(|group| cfg_attrs(&group.attrs).collect()).collect();
+        let item_doc = format!("The exposed groups of [`{set_type}`].");
+        let set_doc = format!("A set of [`{group_item}`] values.");
+        Some(quote! {
+            #[doc = #item_doc]
+            #[#krate::flag(
+                #[doc = #set_doc]
+                #vis #group_set,
+                crate = #krate,
+            )]
+            #vis enum #group_item {
+                #( #variant_attrs #variants, )*
+            }
+
+            impl #group_item #skipped_where {
+                /// The items belonging to the group.
+                #vis const fn items(self) -> #set_type {
+                    match self {
+                        #( #(#cfgs)* Self::#variants => #set_type::#names, )*
+                    }
+                }
+            }
+
+            impl #item_type #skipped_where {
+                /// The set of exposed groups containing the item.
+                #vis const fn group_set(self) -> #group_set {
+                    #[allow(unused_mut)]
+                    let mut groups = <#group_set as #krate::Flags>::EMPTY;
+                    #(
+                        #(#cfgs)*
+                        if #set_type::#names.const_contains(#set_type::from_item(self)) {
+                            groups = groups.const_with(#group_set::from_item(#group_item::#variants));
+                        }
+                    )*
+                    groups
+                }
+            }
+
+            impl #set_type #skipped_where {
+                /// The set of non-empty exposed groups with all their items contained in
+                /// the set.
+                #vis const fn complete_groups(self) -> #group_set {
+                    #[allow(unused_mut)]
+                    let mut groups = <#group_set as #krate::Flags>::EMPTY;
+                    #(
+                        #(#cfgs)*
+                        if !Self::#names.is_empty() && self.const_contains(Self::#names) {
+                            groups = groups.const_with(#group_set::from_item(#group_item::#variants));
+                        }
+                    )*
+                    groups
+                }
+
+                /// The set of exposed groups with at least one of their items contained
+                /// in the set.
+                #vis const fn touched_groups(self) -> #group_set {
+                    #[allow(unused_mut)]
+                    let mut groups = <#group_set as #krate::Flags>::EMPTY;
+                    #(
+                        #(#cfgs)*
+                        if self.const_has_overlap(Self::#names) {
+                            groups = groups.const_with(#group_set::from_item(#group_item::#variants));
+                        }
+                    )*
+                    groups
+                }
+            }
+        })
+    }
+
     fn build_item_type(&self) -> TokenStream {
-        let Self { repr_type, .. } = self;
+        let Self { repr, positions, names, decl, item_type, krate, .. } = self;
+        let (derives, dependent_impls) = self.build_required_impls(
+            item_type,
+            &decl.skip_item_derives,
+            REQUIRED_DERIVES.iter().filter(|name| self.item_derives(name)),
+            |value| if decl.keep_discriminants {
+                quote! { (*#value as i128) }
+            } else {
+                quote! { <Self as #krate::Flag>::bit(*#value) }
+            },
+        );
         let serde_derive = self.build_item_type_serde_derive();
         let flag_impl = self.build_item_type_flag_impl();
+        let impl_checks = build_required_impl_checks(item_type, &decl.skip_item_derives);
         let mut item = self.body.item_enum.clone();
-        for (offset, variant) in item.variants.iter_mut().enumerate() {
-            set_variant_offset(variant, offset);
+        if serde_derive.is_some() {
+            for (variant, name) in item.variants.iter_mut().zip(names) {
+                if variant.ident.unraw() != name {
+                    variant.attrs.push(parse_quote! { #[serde(rename = #name)] });
+                }
+            }
         }
-        let repr = if item.variants.is_empty() {
+        if !decl.keep_discriminants {
+            for (variant, &position) in item.variants.iter_mut().zip(positions) {
+                set_variant_discriminant(variant, repr.discriminant(position));
+            }
+        }
+        let repr = if item.variants.is_empty() || decl.keep_discriminants {
             None
         } else {
-            Some(quote! { #[repr(#repr_type)] })
+            let discriminant_type = repr.discriminant_type(positions.iter().copied().max());
+            Some(quote! { #[repr(#discriminant_type)] })
         };
         quote! {
-            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[derive(#( #derives ),*)]
             #serde_derive
             #repr
             #item
+            #dependent_impls
             #flag_impl
+            #impl_checks
         }
     }
 
     fn build_item_type_flag_impl(&self) -> TokenStream {
-        let Self { item_type, set_type, .. } = self;
+        let skipped_where = self.skipped_derive_where();
+        let Self { item_type, set_type, positions, names, krate, .. } = self;
+        let (count, variants) = self.variants();
+        let indices = 0..count;
+        let variants_index = variants.clone();
+        let variants_from_index = variants.clone();
+        let variants_bit = variants.clone();
+        let variants_from_bit = variants.clone();
+        let variants_name = variants.clone();
+        let variants_from_name = variants;
+        let indices_from_index = indices.clone();
+        let display_impl = self.item_derives("Display").then(|| quote! {
+            impl ::core::fmt::Display for #item_type #skipped_where {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.pad(#krate::Flag::name(*self))
+                }
+            }
+        });
+        let from_str_impl = self.item_derives("FromStr").then(|| quote! {
+            impl ::core::str::FromStr for #item_type #skipped_where {
+                type Err = #krate::ParseItemError;
+
+                fn from_str(name: &str) -> ::core::result::Result<Self, Self::Err> {
+                    <Self as #krate::Flag>::from_name(name)
+                        .ok_or_else(#krate::ParseItemError::new)
+                }
+            }
+        });
+        let vis = &self.vis;
+        let index_body = quote! {
+            match self {
+                #( Self::#variants_index => #indices, )*
+            }
+        };
+        let from_index_body = quote! {
+            match index {
+                #(
+                    #indices_from_index => {
+                        ::core::option::Option::Some(Self::#variants_from_index)
+                    },
+                )*
+                _ => ::core::option::Option::None,
+            }
+        };
+        let bit_body = quote! {
+            match self {
+                #( Self::#variants_bit => #positions, )*
+            }
+        };
+        let from_bit_body = quote! {
+            match position {
+                #( #positions => ::core::option::Option::Some(Self::#variants_from_bit), )*
+                _ => ::core::option::Option::None,
+            }
+        };
+        let (inherent_impl, [index_body, from_index_body, bit_body, from_bit_body]) =
+            if self.decl.item_const_fns {
+                let [index_doc, from_index_doc, bit_doc, from_bit_doc] =
+                    ["index", "from_index", "bit", "from_bit"].map(|name| {
+                        let fq_name = self.doc_path(&format!("Flag::{name}"));
+                        format!("Inherent `const` version of [`{fq_name}`].")
+                    });
+                let inherent_impl = quote! {
+                    impl #item_type #skipped_where {
+                        #[doc = #index_doc]
+                        #[inline(always)]
+                        #vis const fn index(self) -> usize {
+                            #index_body
+                        }
+
+                        #[doc = #from_index_doc]
+                        #[inline(always)]
+                        #vis const fn from_index(index: usize) -> ::core::option::Option<Self> {
+                            #from_index_body
+                        }
+
+                        #[doc = #bit_doc]
+                        #[inline(always)]
+                        #vis const fn bit(self) -> usize {
+                            #bit_body
+                        }
+
+                        #[doc = #from_bit_doc]
+                        #[inline(always)]
+                        #vis const fn from_bit(position: usize) -> ::core::option::Option<Self> {
+                            #from_bit_body
+                        }
+                    }
+                };
+                (Some(inherent_impl), [
+                    quote! { Self::index(self) },
+                    quote! { Self::from_index(index) },
+                    quote! { Self::bit(self) },
+                    quote! { Self::from_bit(position) },
+                ])
+            } else {
+                (None, [index_body, from_index_body, bit_body, from_bit_body])
+            };
         quote! {
-            impl flagnum::Flag for #item_type {
+            #inherent_impl
+
+            impl #krate::Flag for #item_type #skipped_where {
                 type Set = #set_type;
+
+                const COUNT: usize = #count;
+                const NAMES: &'static [&'static str] = &[#( #names ),*];
+
+                fn index(self) -> usize {
+                    #index_body
+                }
+
+                fn from_index(index: usize) -> ::core::option::Option<Self> {
+                    #from_index_body
+                }
+
+                fn bit(self) -> usize {
+                    #bit_body
+                }
+
+                fn from_bit(position: usize) -> ::core::option::Option<Self> {
+                    #from_bit_body
+                }
+
+                fn name(self) -> &'static str {
+                    match self {
+                        #( Self::#variants_name => #names, )*
+                    }
+                }
+
+                fn from_name(name: &str) -> ::core::option::Option<Self> {
+                    match name {
+                        #( #names => ::core::option::Option::Some(Self::#variants_from_name), )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
             }
+
+            #display_impl
+            #from_str_impl
         }
     }
 
     fn build_item_type_serde_derive(&self) -> Option<TokenStream> {
-        if cfg!(feature = "serde") {
-            Some(quote! {
-                #[derive(
-                    flagnum::feature_serde::dep::Deserialize,
-                    flagnum::feature_serde::dep::Serialize,
-                )]
-                #[serde(crate = "flagnum::feature_serde::dep")]
-            })
-        } else {
-            None
+        if !cfg!(feature = "serde") {
+            return None;
         }
+        let derives: Vec<_> = ["Deserialize", "Serialize"]
+            .into_iter()
+            .filter(|name| self.item_derives(name))
+            .map(|name| Ident::new(name, Span::call_site()))
+            .collect();
+        if derives.is_empty() {
+            return None;
+        }
+        let krate = &self.krate;
+        let serde_crate = format!("{}::feature_serde::dep", krate.to_token_stream());
+        Some(quote! {
+            #[derive(#( #krate::feature_serde::dep::#derives ),*)]
+            #[serde(crate = #serde_crate)]
+        })
     }
 
     fn build_set_type(&self) -> TokenStream {
@@ -111,174 +567,548 @@ impl FlagnumContext {
             decl: FlagnumDecl { set: WithAttrs { attrs, vis, .. }, .. },
             ..
         } = self;
+        let (derives, dependent_impls) = self.build_required_impls(
+            set_type,
+            &self.decl.skip_set_derives,
+            REQUIRED_DERIVES.iter().filter(|&&name| name != "Debug" && self.set_derives(name)),
+            |value| quote! { #value.items },
+        );
         let serde_impls = self.build_set_type_serde_impls();
         let flags_impl = self.build_set_type_flags_impl();
         let std_trait_impls = self.build_set_type_std_trait_impls();
         let set_type_impl = self.build_set_type_impl();
+        let impl_checks = build_required_impl_checks(set_type, &self.decl.skip_set_derives);
         quote! {
             #(#attrs)*
-            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[derive(#( #derives ),*)]
             #vis struct #set_type {
                 items: #repr_type,
             }
+            #dependent_impls
             #set_type_impl
             #flags_impl
             #serde_impls
             #std_trait_impls
+            #impl_checks
         }
     }
 
     fn build_set_type_impl(&self) -> TokenStream {
+        let skipped_where = self.skipped_derive_where();
         let Self { set_type, .. } = self;
         let common_fns = self.build_set_type_common_const_fns(false);
+        let set_op_fns = self.build_set_type_set_op_fns(false);
         let const_groups = self.build_set_type_constant_groups();
+        let item_bits_fn = self.build_set_type_item_bits_fn();
+        let exclusive_fns = self.build_set_type_exclusive_fns();
+        let item_accessors = self.build_set_type_item_accessors();
         quote! {
-            impl #set_type {
+            impl #set_type #skipped_where {
                 #const_groups
+                #item_accessors
                 #common_fns
+                #set_op_fns
+                #exclusive_fns
+                #item_bits_fn
+            }
+        }
+    }
+
+    /// Build the single item constants and accessor methods for each item, if enabled.
+    fn build_set_type_item_accessors(&self) -> Option<TokenStream> {
+        let Self { vis, item_type, .. } = self;
+        let rule = self.item_accessors.as_ref()?;
+        let (_, variants) = self.variants();
+        Some(variants.map(|variant| {
+            let span = variant.span();
+            let name = variant.unraw().to_string();
+            let constant = Ident::new(&RenameRule::ScreamingSnakeCase.apply(&name), span);
+            let [getter, setter, toggle] = item_accessor_names(variant, rule);
+            let doc_constant = format!("A set containing only [`{item_type}::{variant}`].");
+            let doc_getter = format!("Whether [`{item_type}::{variant}`] is in the set.");
+            let doc_setter = format!("Insert or remove [`{item_type}::{variant}`].");
+            let doc_toggle = format!("Toggle whether [`{item_type}::{variant}`] is in the set.");
+            quote! {
+                #[doc = #doc_constant]
+                #vis const #constant: Self = Self::from_item(#item_type::#variant);
+
+                #[doc = #doc_getter]
+                #[inline(always)]
+                #vis const fn #getter(self) -> bool {
+                    self.const_contains(Self::#constant)
+                }
+
+                #[doc = #doc_setter]
+                #[inline(always)]
+                #vis const fn #setter(&mut self, value: bool) {
+                    if value {
+                        self.const_insert(Self::#constant);
+                    } else {
+                        self.const_remove(Self::#constant);
+                    }
+                }
+
+                #[doc = #doc_toggle]
+                #[inline(always)]
+                #vis const fn #toggle(&mut self) {
+                    self.const_toggle(Self::#constant);
+                }
+            }
+        }).collect())
+    }
+
+    /// Build the functions for working with exclusive groups, if any are declared.
+    fn build_set_type_exclusive_fns(&self) -> Option<TokenStream> {
+        let Self { vis, item_type, krate, .. } = self;
+        let groups: Vec<_> = self.decl.groups.iter().filter(|group| group.value.exclusive).collect();
+        if groups.is_empty() {
+            return None;
+        }
+        let names: Vec<_> = groups.iter().map(|group| &group.value.name).collect();
+        let name_strs = names.iter().map(|name| name.unraw().to_string());
+        let cfgs: Vec<Vec<_>> = groups.iter().map(|group| cfg_attrs(&group.attrs).collect()).collect();
+        Some(quote! {
+            /// Insert an item after removing all other items of the exclusive groups
+            /// containing it.
+            #vis const fn set_exclusive(&mut self, item: #item_type) {
+                let item = Self::from_item(item);
+                #(
+                    #(#cfgs)*
+                    if Self::#names.const_contains(item) {
+                        self.const_remove(Self::#names);
+                    }
+                )*
+                self.const_insert(item);
+            }
+
+            /// The item selected in a group, if exactly one of its items is contained
+            /// in the set.
+            #vis const fn selected_in(self, group: Self) -> ::core::option::Option<#item_type> {
+                let selected = self.const_overlap(group);
+                if selected.len() != 1 {
+                    return ::core::option::Option::None;
+                }
+                let mut items = <Self as #krate::Flags>::ITEMS;
+                while let ::core::option::Option::Some((&first, rest)) = items.split_first() {
+                    if selected.const_contains(Self::from_item(first)) {
+                        return ::core::option::Option::Some(first);
+                    }
+                    items = rest;
+                }
+                ::core::option::Option::None
+            }
+
+            /// Verify that the set contains at most one item of each exclusive group.
+            ///
+            /// The error reports the first violated group in declaration order.
+            #vis const fn validate_exclusive(
+                self,
+            ) -> ::core::result::Result<(), #krate::ExclusiveGroupError<Self>> {
+                #(
+                    #(#cfgs)*
+                    if self.const_overlap(Self::#names).len() > 1 {
+                        return ::core::result::Result::Err(#krate::ExclusiveGroupError::new(
+                            #name_strs,
+                            self.const_overlap(Self::#names),
+                        ));
+                    }
+                )*
+                ::core::result::Result::Ok(())
+            }
+        })
+    }
+
+    fn build_set_type_item_bits_fn(&self) -> TokenStream {
+        let Self { repr, repr_type, item_type, positions, decl, .. } = self;
+        let item_bits = if decl.keep_discriminants {
+            let (_, variants) = self.variants();
+            let bits = positions.iter().map(|&position| repr.position_bits(position));
+            quote! {
+                match item {
+                    #( #item_type::#variants => #bits, )*
+                }
+            }
+        } else {
+            repr.item_bits(quote! { item })
+        };
+        quote! {
+            #[inline(always)]
+            const fn __item_bits(item: #item_type) -> #repr_type {
+                #item_bits
             }
         }
     }
 
     fn build_set_type_constant_groups(&self) -> TokenStream {
         let Self {
-            repr_type, item_type,
-            body: FlagnumEnum { grouped, .. },
+            repr,
+            group_positions,
             decl: FlagnumDecl { groups, .. },
             ..
         } = self;
-        groups.iter().map(|WithAttrs { value: group, attrs, vis }| {
-            let members = grouped
-                .get(group)
-                .map(|members| members.as_slice())
-                .unwrap_or_default()
-                .iter()
-                .map(|member| quote! { #item_type::#member as #repr_type });
+        groups.iter().zip(group_positions).map(|(WithAttrs { value, attrs, vis }, members)| {
+            let group = &value.name;
+            let items = repr.literal(members.iter().copied());
+            let methods = self.decl.group_methods.then(|| {
+                self.build_set_type_group_methods(group, attrs, vis.as_ref())
+            });
             quote! {
                 #(#attrs)*
                 #vis const #group: Self = Self {
-                    items: 0 #( | #members )*,
+                    items: #items,
                 };
+
+                #methods
             }
         }).collect()
     }
 
-    fn build_set_type_std_trait_impls(&self) -> TokenStream {
-        let Self { set_type, item_type, repr_type, .. } = self;
+    /// Build the predicate and mutator methods for a single group.
+    ///
+    /// The methods use the visibility and non-documentation attributes of the group.
+    fn build_set_type_group_methods(
+        &self,
+        group: &Ident,
+        attrs: &[Attribute],
+        vis: Option<&Visibility>,
+    ) -> TokenStream {
+        let attrs = attrs.iter().filter(|attr| !attr.path.is_ident("doc"));
+        let attrs = quote! { #(#attrs)* };
+        let [contains_all, contains_any, part, clear] = group_method_names(group);
+        let doc_contains_all = format!("Whether all items of [`Self::{group}`] are in the set.");
+        let doc_contains_any = format!("Whether any item of [`Self::{group}`] is in the set.");
+        let doc_part = format!("The items of [`Self::{group}`] contained in the set.");
+        let doc_clear = format!("Remove all items of [`Self::{group}`] from the set.");
         quote! {
-            impl std::fmt::Debug for #set_type {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    f.debug_set().entries(self.into_iter()).finish()
+            #[doc = #doc_contains_all]
+            #attrs
+            #[inline(always)]
+            #vis const fn #contains_all(self) -> bool {
+                self.const_contains(Self::#group)
+            }
+
+            #[doc = #doc_contains_any]
+            #attrs
+            #[inline(always)]
+            #vis const fn #contains_any(self) -> bool {
+                self.const_has_overlap(Self::#group)
+            }
+
+            #[doc = #doc_part]
+            #attrs
+            #[inline(always)]
+            #vis const fn #part(self) -> Self {
+                self.const_overlap(Self::#group)
+            }
+
+            #[doc = #doc_clear]
+            #attrs
+            #[inline(always)]
+            #vis const fn #clear(&mut self) {
+                self.const_remove(Self::#group);
+            }
+        }
+    }
+
+    fn build_set_type_std_trait_impls(&self) -> TokenStream {
+        let skipped_bounds = self.skipped_derive_bounds();
+        let skipped_where = self.skipped_derive_where();
+        let Self { set_type, item_type, repr, separator, krate, .. } = self;
+        let group_arms = self.decl.groups.iter().map(|WithAttrs { value, attrs, .. }| {
+            let group = &value.name;
+            let cfg_attrs = cfg_attrs(attrs);
+            let name = group.unraw().to_string();
+            quote! { #(#cfg_attrs)* #name => ::core::option::Option::Some(Self::#group), }
+        });
+        let empty = repr.empty();
+        let debug_unknown = if self.decl.retain_unknown_bits {
+            let unknown = repr.difference(
+                quote! { self.items },
+                quote! { <Self as #krate::Flags>::FULL.items },
+            );
+            let unknown_is_empty = repr.is_empty(quote! { unknown });
+            Some(quote! {
+                let unknown = #unknown;
+                if !#unknown_is_empty {
+                    set.entry(&#krate::UnknownBits::new(unknown));
+                }
+            })
+        } else {
+            None
+        };
+        let union_item = repr.union(quote! { bits }, quote! { Self::__item_bits(item) });
+        let union_next = repr.union_assign(quote! { self.items }, quote! { next.into().items });
+        let ops_impls = self.build_set_type_ops_impls();
+        let display_impl = self.set_derives("Display").then(|| quote! {
+            impl ::core::fmt::Display for #set_type #skipped_where {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #krate::text::fmt_set(*self, #separator, f)
+                }
+            }
+        });
+        let from_str_impl = self.set_derives("FromStr").then(|| quote! {
+            impl ::core::str::FromStr for #set_type #skipped_where {
+                type Err = #krate::ParseSetError;
+
+                fn from_str(text: &str) -> ::core::result::Result<Self, Self::Err> {
+                    #krate::text::parse_set(text, #separator, |token| {
+                        match <#item_type as #krate::Flag>::from_name(token) {
+                            ::core::option::Option::Some(item) => {
+                                ::core::option::Option::Some(Self::from(item))
+                            },
+                            ::core::option::Option::None => match token {
+                                #( #group_arms )*
+                                _ => ::core::option::Option::None,
+                            },
+                        }
+                    })
+                }
+            }
+        });
+        let radix_impls: [(&str, usize, bool, &str); 4] = [
+            ("Binary", 1, false, "0b"),
+            ("Octal", 3, false, "0o"),
+            ("LowerHex", 4, false, "0x"),
+            ("UpperHex", 4, true, "0x"),
+        ];
+        let radix_impls: TokenStream = radix_impls
+            .into_iter()
+            .filter(|(name, ..)| self.set_derives(name))
+            .map(|(name, digit_bits, uppercase, prefix)| {
+                let name = Ident::new(name, Span::call_site());
+                quote! {
+                    impl ::core::fmt::#name for #set_type #skipped_where {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            #krate::text::fmt_radix(self.items, #digit_bits, #uppercase, #prefix, f)
+                        }
+                    }
+                }
+            })
+            .collect();
+        let vec_impls = cfg!(feature = "alloc").then(|| quote! {
+            impl ::core::convert::From<#krate::feature_alloc::Vec<#item_type>> for #set_type #skipped_where {
+                fn from(items: #krate::feature_alloc::Vec<#item_type>) -> Self {
+                    items.into_iter().collect()
+                }
+            }
+
+            impl ::core::convert::From<&#krate::feature_alloc::Vec<#item_type>> for #set_type #skipped_where {
+                fn from(items: &#krate::feature_alloc::Vec<#item_type>) -> Self {
+                    items.iter().copied().collect()
+                }
+            }
+        });
+        let debug_impl = self.set_derives("Debug").then(|| quote! {
+            impl ::core::fmt::Debug for #set_type #skipped_where {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut set = f.debug_set();
+                    set.entries(::core::iter::IntoIterator::into_iter(self));
+                    #debug_unknown
+                    set.finish()
                 }
             }
+        });
+        quote! {
+            #display_impl
+            #from_str_impl
+            #radix_impls
+            #debug_impl
 
-            impl From<()> for #set_type {
+            impl ::core::convert::From<()> for #set_type #skipped_where {
                 fn from(_: ()) -> Self {
-                    Self { items: 0 }
+                    Self { items: #empty }
                 }
             }
 
-            impl From<#item_type> for #set_type {
+            impl ::core::convert::From<#item_type> for #set_type #skipped_where {
                 fn from(item: #item_type) -> Self {
-                    Self { items: item as #repr_type }
+                    Self { items: Self::__item_bits(item) }
                 }
             }
 
-            impl From<Option<#item_type>> for #set_type {
-                fn from(item: Option<#item_type>) -> Self {
-                    if let Some(item) = item {
-                        Self { items: item as #repr_type }
+            impl ::core::convert::From<::core::option::Option<#item_type>> for #set_type #skipped_where {
+                fn from(item: ::core::option::Option<#item_type>) -> Self {
+                    if let ::core::option::Option::Some(item) = item {
+                        Self { items: Self::__item_bits(item) }
                     } else {
-                        Self { items: 0 }
+                        Self { items: #empty }
                     }
                 }
             }
 
-            impl<const N: usize> From<[#item_type; N]> for #set_type {
+            impl<const N: usize> ::core::convert::From<[#item_type; N]> for #set_type #skipped_where {
                 fn from(items: [#item_type; N]) -> Self {
                     items.into_iter().collect()
                 }
             }
 
-            impl<const N: usize> From<&[#item_type; N]> for #set_type {
+            impl<const N: usize> ::core::convert::From<&[#item_type; N]> for #set_type #skipped_where {
                 fn from(items: &[#item_type; N]) -> Self {
                     items.as_slice().into()
                 }
             }
 
-            impl From<&[#item_type]> for #set_type {
+            impl ::core::convert::From<&[#item_type]> for #set_type #skipped_where {
                 fn from(items: &[#item_type]) -> Self {
                     items.iter().copied().collect()
                 }
             }
 
-            impl From<Vec<#item_type>> for #set_type {
-                fn from(items: Vec<#item_type>) -> Self {
-                    items.into_iter().collect()
-                }
-            }
-
-            impl From<&Vec<#item_type>> for #set_type {
-                fn from(items: &Vec<#item_type>) -> Self {
-                    items.iter().copied().collect()
-                }
-            }
+            #vec_impls
 
-            impl FromIterator<#item_type> for #set_type {
+            impl ::core::iter::FromIterator<#item_type> for #set_type #skipped_where {
                 fn from_iter<I>(iter: I) -> Self
                 where
-                    I: IntoIterator<Item = #item_type>,
+                    I: ::core::iter::IntoIterator<Item = #item_type>,
                 {
                     Self {
                         items: iter
                             .into_iter()
-                            .map(|item| item as #repr_type)
-                            .fold(0, std::ops::BitOr::bitor),
+                            .fold(#empty, |bits, item| #union_item),
                     }
                 }
             }
 
-            impl IntoIterator for #set_type {
+            impl ::core::iter::IntoIterator for #set_type #skipped_where {
                 type Item = #item_type;
-                type IntoIter = flagnum::Iter<Self>;
+                type IntoIter = #krate::Iter<Self>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    flagnum::Iter::new(self)
+                    #krate::Iter::new(self)
                 }
             }
 
-            impl IntoIterator for &#set_type {
+            impl ::core::iter::IntoIterator for &#set_type #skipped_where {
                 type Item = #item_type;
-                type IntoIter = flagnum::Iter<#set_type>;
+                type IntoIter = #krate::Iter<#set_type>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    flagnum::Iter::new(*self)
+                    #krate::Iter::new(*self)
                 }
             }
 
-            impl<T> Extend<T> for #set_type
+            impl<T> ::core::iter::Extend<T> for #set_type
             where
-                T: Into<#set_type>,
+                #skipped_bounds
+                T: ::core::convert::Into<#set_type>,
             {
                 fn extend<I>(&mut self, iter: I)
                 where
-                    I: IntoIterator<Item = T>,
+                    I: ::core::iter::IntoIterator<Item = T>,
                 {
                     for next in iter.into_iter() {
-                        self.items |= next.into().items;
+                        #union_next
+                    }
+                }
+            }
+
+            #ops_impls
+        }
+    }
+
+    fn build_set_type_ops_impls(&self) -> TokenStream {
+        let skipped_bounds = self.skipped_derive_bounds();
+        let skipped_where = self.skipped_derive_where();
+        let Self { set_type, item_type, krate, .. } = self;
+        let binary_ops = [
+            (quote! { BitOr }, quote! { bitor }, quote! { BitOrAssign }, quote! { bitor_assign },
+                quote! { #krate::Flags::with(self, other) },
+                quote! { #krate::Flags::insert(self, other); }),
+            (quote! { BitAnd }, quote! { bitand }, quote! { BitAndAssign }, quote! { bitand_assign },
+                quote! { #krate::Flags::overlap(self, other) },
+                quote! { #krate::Flags::keep(self, other); }),
+            (quote! { Sub }, quote! { sub }, quote! { SubAssign }, quote! { sub_assign },
+                quote! { #krate::Flags::without(self, other) },
+                quote! { #krate::Flags::remove(self, other); }),
+            (quote! { BitXor }, quote! { bitxor }, quote! { BitXorAssign }, quote! { bitxor_assign },
+                quote! { #krate::Flags::symmetric_difference(self, other) },
+                quote! { #krate::Flags::toggle(self, other); }),
+        ];
+        let binary_ops = binary_ops.into_iter().map(|(op, op_fn, assign, assign_fn, body, assign_body)| {
+            quote! {
+                impl<T> ::core::ops::#op<T> for #set_type
+                where
+                    #skipped_bounds
+                    T: ::core::convert::Into<#set_type>,
+                {
+                    type Output = Self;
+
+                    fn #op_fn(self, other: T) -> Self {
+                        #body
+                    }
+                }
+
+                impl<T> ::core::ops::#assign<T> for #set_type
+                where
+                    #skipped_bounds
+                    T: ::core::convert::Into<#set_type>,
+                {
+                    fn #assign_fn(&mut self, other: T) {
+                        #assign_body
+                    }
+                }
+
+                impl<T> ::core::ops::#op<T> for #item_type
+                where
+                    #skipped_bounds
+                    T: ::core::convert::Into<#set_type>,
+                {
+                    type Output = #set_type;
+
+                    fn #op_fn(self, other: T) -> #set_type {
+                        ::core::ops::#op::#op_fn(#set_type::from(self), other)
                     }
                 }
             }
+        });
+        quote! {
+            #( #binary_ops )*
+
+            impl ::core::ops::Not for #set_type #skipped_where {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    #krate::Flags::missing(self)
+                }
+            }
+
+            impl ::core::ops::Not for #item_type #skipped_where {
+                type Output = #set_type;
+
+                fn not(self) -> #set_type {
+                    #krate::Flags::missing(#set_type::from(self))
+                }
+            }
         }
     }
 
     fn build_set_type_common_const_fns(&self, in_trait: bool) -> TokenStream {
-        let Self { vis, item_type, repr_type, .. } = self;
+        let Self { vis, item_type, repr, repr_type, krate, .. } = self;
+        let empty = repr.empty();
+        let full_items = quote! { <Self as #krate::Flags>::FULL.items };
+        let unknown = repr.difference(quote! { bits }, full_items.clone());
+        let unknown_is_empty = repr.is_empty(quote! { unknown });
+        let truncated = repr.intersection(quote! { bits }, full_items.clone());
+        let retained = if self.decl.retain_unknown_bits {
+            quote! { bits }
+        } else {
+            truncated.clone()
+        };
+        let known_items = self.known_bits(quote! { self.items });
+        let union_first_item = repr.union_assign(quote! { value }, quote! { Self::__item_bits(first) });
+        let union_first_set = repr.union_assign(quote! { value }, quote! { first.items });
+        let mut missing = repr.difference(full_items.clone(), quote! { self.items });
+        if self.decl.retain_unknown_bits {
+            missing = repr.union(missing, repr.difference(quote! { self.items }, full_items.clone()));
+        }
+        let count = repr.count(known_items.clone());
+        let is_empty = repr.is_empty(known_items.clone());
+        let is_full = repr.eq(known_items, full_items);
         let with_prefix = |rel_name, body| {
             if in_trait {
                 body
             } else {
-                let fq_rel_name = format!("flagnum::Flags::{rel_name}");
+                let fq_rel_name = self.doc_path(&format!("Flags::{rel_name}"));
                 quote! {
                     #[doc = "Inherent `const` version of [`"]
                     #[doc = #fq_rel_name]
@@ -291,14 +1121,14 @@ impl FlagnumContext {
         TokenStream::from_iter([
             with_prefix("from_item", quote! {
                 fn from_item(item: #item_type) -> Self {
-                    Self { items: item as #repr_type }
+                    Self { items: Self::__item_bits(item) }
                 }
             }),
             with_prefix("from_items", quote! {
                 fn from_items(mut items: &[#item_type]) -> Self {
-                    let mut value = 0;
-                    while let Some((&first, rest)) = items.split_first() {
-                        value |= first as #repr_type;
+                    let mut value = #empty;
+                    while let ::core::option::Option::Some((&first, rest)) = items.split_first() {
+                        #union_first_item
                         items = rest;
                     }
                     Self { items: value }
@@ -306,122 +1136,185 @@ impl FlagnumContext {
             }),
             with_prefix("from_sets", quote! {
                 fn from_sets(mut sets: &[Self]) -> Self {
-                    let mut value = 0;
-                    while let Some((&first, rest)) = sets.split_first() {
-                        value |= first.items;
+                    let mut value = #empty;
+                    while let ::core::option::Option::Some((&first, rest)) = sets.split_first() {
+                        #union_first_set
                         sets = rest;
                     }
                     Self { items: value }
                 }
             }),
+            with_prefix("from_bits", quote! {
+                fn from_bits(
+                    bits: #repr_type,
+                ) -> ::core::result::Result<Self, #krate::UnknownBits<#repr_type>> {
+                    let unknown = #unknown;
+                    if #unknown_is_empty {
+                        ::core::result::Result::Ok(Self { items: bits })
+                    } else {
+                        ::core::result::Result::Err(#krate::UnknownBits::new(unknown))
+                    }
+                }
+            }),
+            with_prefix("from_bits_truncate", quote! {
+                fn from_bits_truncate(bits: #repr_type) -> Self {
+                    Self { items: #truncated }
+                }
+            }),
+            with_prefix("from_bits_retain", quote! {
+                fn from_bits_retain(bits: #repr_type) -> Self {
+                    Self { items: #retained }
+                }
+            }),
+            with_prefix("bits", quote! {
+                fn bits(self) -> #repr_type {
+                    self.items
+                }
+            }),
             with_prefix("len", quote! {
                 fn len(self) -> usize {
-                    self.items.count_ones() as usize
+                    #count
                 }
             }),
             with_prefix("is_empty", quote! {
                 fn is_empty(self) -> bool {
-                    self.items == 0
+                    #is_empty
                 }
             }),
             with_prefix("is_full", quote! {
                 fn is_full(self) -> bool {
-                    self.items == <Self as flagnum::Flags>::FULL.items
+                    #is_full
+                }
+            }),
+            with_prefix("missing", quote! {
+                fn missing(self) -> Self {
+                    Self { items: #missing }
+                }
+            }),
+            with_prefix("invert", quote! {
+                fn invert(&mut self) {
+                    self.items = #missing;
                 }
             }),
         ])
     }
 
-    fn build_set_type_flags_impl(&self) -> TokenStream {
-        let Self { set_type, item_type, repr_type, .. } = self;
-        let common_fns = self.build_set_type_common_const_fns(true);
-        let (_, variants) = self.variants();
-        let variants_items = variants.clone();
-        quote! {
-            impl flagnum::Flags for #set_type {
-                type Item = #item_type;
-
-                const EMPTY: Self = Self { items: 0 };
-                const FULL: Self = Self {
-                    items: 0 #( | #item_type::#variants as #repr_type )*,
-                };
-                const ITEMS: &'static [#item_type] = &[#( #item_type::#variants_items ),*];
-
-                #common_fns
-
-                fn contains<T>(self, other: T) -> bool
-                where
-                    T: Into<Self>,
-                {
-                    let other = other.into();
-                    (self.items & other.items) == other.items
-                }
-
-                fn overlap<T>(self, other: T) -> Self
-                where
-                    T: Into<Self>,
-                {
-                    Self { items: self.items & other.into().items }
-                }
-
-                fn has_overlap<T>(self, other: T) -> bool
-                where
-                    T: Into<Self>,
-                {
-                    (self.items & other.into().items) != 0
-                }
-
-                fn with<T>(self, other: T) -> Self
-                where
-                    T: Into<Self>,
-                {
-                    Self { items: self.items | other.into().items }
-                }
-
-                fn without<T>(self, other: T) -> Self
-                where
-                    T: Into<Self>,
-                {
-                    Self { items: self.items & !other.into().items }
-                }
-
-                fn missing(self) -> Self {
-                    Self { items: <Self as flagnum::Flags>::FULL.items & !self.items }
+    fn build_set_type_set_op_fns(&self, in_trait: bool) -> TokenStream {
+        let Self { vis, repr, .. } = self;
+        let with_prefix = |rel_name: &str, receiver, output: Option<TokenStream>, body| {
+            let output = output.map(|output| quote! { -> #output });
+            if in_trait {
+                let name = Ident::new(rel_name, Span::call_site());
+                quote! {
+                    fn #name<T>(#receiver, other: T) #output
+                    where
+                        T: ::core::convert::Into<Self>,
+                    {
+                        let other: Self = other.into();
+                        #body
+                    }
                 }
-
-                fn invert(&mut self) {
-                    self.items = <Self as flagnum::Flags>::FULL.items & !self.items;
+            } else {
+                let name = format_ident!("const_{rel_name}");
+                let fq_rel_name = self.doc_path(&format!("Flags::{rel_name}"));
+                quote! {
+                    #[doc = "Inherent `const` version of [`"]
+                    #[doc = #fq_rel_name]
+                    #[doc = "`] taking a concrete set."]
+                    #[inline(always)]
+                    #vis const fn #name(#receiver, other: Self) #output {
+                        #body
+                    }
                 }
+            }
+        };
+        let self_items = quote! { self.items };
+        let other_items = quote! { other.items };
+        let is_subset_of = repr.is_empty(repr.difference(self_items.clone(), other_items.clone()));
+        let is_superset_of = repr.is_empty(repr.difference(other_items.clone(), self_items.clone()));
+        let is_equal = repr.eq(self_items.clone(), other_items.clone());
+        let overlap = repr.intersection(self_items.clone(), other_items.clone());
+        let is_disjoint = repr.is_empty(overlap.clone());
+        let with = repr.union(self_items.clone(), other_items.clone());
+        let without = repr.difference(self_items.clone(), other_items.clone());
+        let symmetric_difference = repr.symmetric_difference(self_items.clone(), other_items.clone());
+        let insert = repr.union_assign(self_items.clone(), other_items.clone());
+        let remove = repr.difference_assign(self_items.clone(), other_items.clone());
+        let keep = repr.intersection_assign(self_items.clone(), other_items.clone());
+        let toggle = repr.symmetric_difference_assign(self_items, other_items);
+        TokenStream::from_iter([
+            with_prefix("contains", quote! { self }, Some(quote! { bool }), is_superset_of.clone()),
+            with_prefix("has_overlap", quote! { self }, Some(quote! { bool }), quote! {
+                !#is_disjoint
+            }),
+            with_prefix("is_subset_of", quote! { self }, Some(quote! { bool }), is_subset_of.clone()),
+            with_prefix("is_superset_of", quote! { self }, Some(quote! { bool }), is_superset_of),
+            with_prefix("overlap", quote! { self }, Some(quote! { Self }), quote! {
+                Self { items: #overlap }
+            }),
+            with_prefix("with", quote! { self }, Some(quote! { Self }), quote! {
+                Self { items: #with }
+            }),
+            with_prefix("without", quote! { self }, Some(quote! { Self }), quote! {
+                Self { items: #without }
+            }),
+            with_prefix("is_strict_subset_of", quote! { self }, Some(quote! { bool }), quote! {
+                #is_subset_of && !#is_equal
+            }),
+            with_prefix("is_disjoint", quote! { self }, Some(quote! { bool }), is_disjoint),
+            with_prefix("symmetric_difference", quote! { self }, Some(quote! { Self }), quote! {
+                Self { items: #symmetric_difference }
+            }),
+            with_prefix("insert", quote! { &mut self }, None, insert),
+            with_prefix("remove", quote! { &mut self }, None, remove),
+            with_prefix("keep", quote! { &mut self }, None, keep),
+            with_prefix("toggle", quote! { &mut self }, None, toggle),
+        ])
+    }
 
-                fn insert<T>(&mut self, other: T)
-                where
-                    T: Into<Self>,
-                {
-                    self.items |= other.into().items;
-                }
+    fn build_set_type_flags_impl(&self) -> TokenStream {
+        let skipped_where = self.skipped_derive_where();
+        let Self { set_type, item_type, repr, repr_type, positions, krate, .. } = self;
+        let group_entries = self.decl.groups.iter()
+            .filter(|group| group.vis.is_some())
+            .map(|WithAttrs { value, attrs, .. }| {
+                let group = &value.name;
+                let cfg_attrs = cfg_attrs(attrs);
+                let name = group.unraw().to_string();
+                quote! { #(#cfg_attrs)* (#name, Self::#group) }
+            });
+        let common_fns = self.build_set_type_common_const_fns(true);
+        let set_op_fns = self.build_set_type_set_op_fns(true);
+        let (_, variants) = self.variants();
+        let empty = repr.empty();
+        let full = repr.literal(positions.iter().copied());
+        let bits_in_item_order = positions.windows(2).all(|pair| pair[0] < pair[1]);
+        let self_items = quote! { self.items };
+        let item_value = quote! { item_value };
+        let has_item = repr.is_empty(repr.intersection(self_items.clone(), item_value.clone()));
+        let remove_item = repr.difference_assign(self_items, item_value);
+        quote! {
+            impl #krate::Flags for #set_type #skipped_where {
+                type Item = #item_type;
+                type Bits = #repr_type;
 
-                fn remove<T>(&mut self, other: T)
-                where
-                    T: Into<Self>,
-                {
-                    self.items &= !other.into().items;
-                }
+                const EMPTY: Self = Self { items: #empty };
+                const FULL: Self = Self { items: #full };
+                const ITEMS: &'static [#item_type] = &[#( #item_type::#variants ),*];
+                const GROUPS: &'static [(&'static str, Self)] = &[#( #group_entries ),*];
+                const __BITS_IN_ITEM_ORDER: bool = #bits_in_item_order;
 
-                fn keep<T>(&mut self, other: T)
-                where
-                    T: Into<Self>,
-                {
-                    self.items &= other.into().items;
-                }
+                #common_fns
+                #set_op_fns
 
                 fn retain<F>(&mut self, mut is_retained: F)
                 where
                     F: FnMut(#item_type) -> bool,
                 {
-                    for (offset, &item) in <Self as flagnum::Flags>::ITEMS.iter().enumerate() {
-                        let item_value = 1 << offset;
-                        if (self.items & item_value) != 0 && !is_retained(item) {
-                            self.items &= !item_value;
+                    for &item in <Self as #krate::Flags>::ITEMS {
+                        let item_value = Self::__item_bits(item);
+                        if !#has_item && !is_retained(item) {
+                            #remove_item
                         }
                     }
                 }
@@ -430,7 +1323,7 @@ impl FlagnumContext {
                 where
                     F: FnMut(#item_type) -> bool,
                 {
-                    self.retain(is_retained);
+                    <Self as #krate::Flags>::retain(&mut self, is_retained);
                     self
                 }
             }
@@ -438,30 +1331,58 @@ impl FlagnumContext {
     }
 
     fn build_set_type_serde_impls(&self) -> Option<TokenStream> {
-        let Self { set_type, .. } = self;
+        let skipped_where = self.skipped_derive_where();
+        let Self { set_type, repr, krate, .. } = self;
         if cfg!(feature = "serde") {
-            Some(quote! {
-                impl<'de> flagnum::feature_serde::dep::Deserialize<'de> for #set_type {
-                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            let (deserialize, serialize) = if self.decl.retain_unknown_bits {
+                let unknown = repr.difference(
+                    quote! { self.items },
+                    quote! { <Self as #krate::Flags>::FULL.items },
+                );
+                (quote! {
+                    deserializer.deserialize_tuple(2, #krate::feature_serde::RetainingSetVisitor::new())
+                }, quote! {
+                    use #krate::feature_serde::dep::ser::SerializeTuple;
+                    let mut tuple = serializer.serialize_tuple(2)?;
+                    tuple.serialize_element(&#krate::feature_serde::KnownItems(*self))?;
+                    tuple.serialize_element(&#unknown)?;
+                    tuple.end()
+                })
+            } else {
+                (quote! {
+                    deserializer.deserialize_seq(#krate::feature_serde::SetVisitor::new())
+                }, quote! {
+                    use #krate::feature_serde::dep::ser::SerializeSeq;
+                    let mut seq = serializer.serialize_seq(::core::option::Option::Some(self.len()))?;
+                    for item in *self {
+                        seq.serialize_element(&item)?;
+                    }
+                    seq.end()
+                })
+            };
+            let deserialize_impl = self.set_derives("Deserialize").then(|| quote! {
+                impl<'de> #krate::feature_serde::dep::Deserialize<'de> for #set_type #skipped_where {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
                     where
-                        D: flagnum::feature_serde::dep::Deserializer<'de>,
+                        D: #krate::feature_serde::dep::Deserializer<'de>,
                     {
-                        deserializer.deserialize_seq(flagnum::feature_serde::SetVisitor::new())
+                        #deserialize
                     }
                 }
-                impl flagnum::feature_serde::dep::Serialize for #set_type {
-                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            });
+            let serialize_impl = self.set_derives("Serialize").then(|| quote! {
+                impl #krate::feature_serde::dep::Serialize for #set_type #skipped_where {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
                     where
-                        S: flagnum::feature_serde::dep::Serializer,
+                        S: #krate::feature_serde::dep::Serializer,
                     {
-                        use flagnum::feature_serde::dep::ser::SerializeSeq;
-                        let mut seq = serializer.serialize_seq(Some(self.len()))?;
-                        for item in *self {
-                            seq.serialize_element(&item)?;
-                        }
-                        seq.end()
+                        #serialize
                     }
                 }
+            });
+            Some(quote! {
+                #deserialize_impl
+                #serialize_impl
             })
         } else {
             None
@@ -469,16 +1390,45 @@ impl FlagnumContext {
     }
 }
 
-pub enum FlagnumRepr { U8, U16, U32, U64, U128 }
+/// The integer representation of a set, or an array of words and the path to the
+/// flagnum crate providing the word operations.
+pub enum FlagnumRepr { U8, U16, U32, U64, U128, Words(usize, Path) }
 
 impl FlagnumRepr {
-    fn try_from_enum_len(len: usize) -> Option<Self> {
-        if len <= 8 { Some(Self::U8) }
-        else if len <= 16 { Some(Self::U16) }
-        else if len <= 32 { Some(Self::U32) }
-        else if len <= 64 { Some(Self::U64) }
-        else if len <= 128 { Some(Self::U128) }
-        else { None }
+    fn from_bit_len(len: Option<usize>, krate: &Path) -> Self {
+        let len = len.unwrap_or_default();
+        if len <= 8 { Self::U8 }
+        else if len <= 16 { Self::U16 }
+        else if len <= 32 { Self::U32 }
+        else if len <= 64 { Self::U64 }
+        else if len <= 128 { Self::U128 }
+        else { Self::Words(len.div_ceil(WORD_BITS), krate.clone()) }
+    }
+
+    fn try_from_ident(ident: &Ident) -> syn::Result<Self> {
+        match ident.to_string().as_str() {
+            "u8" => Ok(Self::U8),
+            "u16" => Ok(Self::U16),
+            "u32" => Ok(Self::U32),
+            "u64" => Ok(Self::U64),
+            "u128" => Ok(Self::U128),
+            _ => Err(Error::new(
+                ident.span(),
+                "Expected one of `u8`, `u16`, `u32`, `u64` or `u128` as flagnum representation",
+            )),
+        }
+    }
+
+    /// The number of bit positions available in this representation.
+    fn capacity(&self) -> usize {
+        match self {
+            Self::U8 => 8,
+            Self::U16 => 16,
+            Self::U32 => 32,
+            Self::U64 => 64,
+            Self::U128 => 128,
+            Self::Words(len, _) => len * WORD_BITS,
+        }
     }
 
     fn name(&self) -> &'static str {
@@ -488,21 +1438,786 @@ impl FlagnumRepr {
             Self::U32 => "u32",
             Self::U64 => "u64",
             Self::U128 => "u128",
+            Self::Words(..) => "words",
         }
     }
 
     fn to_ident(&self) -> Ident {
         parse_str(self.name()).unwrap()
     }
+
+    fn to_type(&self) -> TokenStream {
+        match self {
+            Self::Words(len, krate) => quote! { [#krate::words::Word; #len] },
+            _ => self.to_ident().into_token_stream(),
+        }
+    }
+
+    /// The type used for the discriminants of the item enum.
+    fn discriminant_type(&self, max_position: Option<usize>) -> TokenStream {
+        match self {
+            Self::Words(..) => {
+                let max_position = max_position.unwrap_or_default();
+                let repr = if max_position <= u8::MAX.into() { Self::U8 }
+                    else if max_position <= u16::MAX.into() { Self::U16 }
+                    else { Self::U32 };
+                repr.to_type()
+            },
+            _ => self.to_type(),
+        }
+    }
+
+    /// The discriminant value of an item at the given bit position.
+    ///
+    /// Single integer representations use the bit value, the word array representation
+    /// uses the position itself.
+    fn discriminant(&self, position: usize) -> Literal {
+        match self {
+            Self::Words(..) => Literal::usize_unsuffixed(position),
+            _ => Literal::u128_unsuffixed(1 << position),
+        }
+    }
+
+    fn item_bits(&self, item: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(len, krate) => quote! { #krate::words::bit::<#len>(#item as usize) },
+            _ => {
+                let repr_type = self.to_type();
+                quote! { #item as #repr_type }
+            },
+        }
+    }
+
+    fn position_bits(&self, position: usize) -> TokenStream {
+        match self {
+            Self::Words(len, krate) => quote! { #krate::words::bit::<#len>(#position) },
+            _ => self.literal([position]),
+        }
+    }
+
+    fn literal(&self, positions: impl IntoIterator<Item = usize>) -> TokenStream {
+        match self {
+            Self::Words(len, _) => {
+                let mut words = vec![0u64; *len];
+                for position in positions {
+                    words[position / WORD_BITS] |= 1 << (position % WORD_BITS);
+                }
+                let words = words.into_iter().map(Literal::u64_unsuffixed);
+                quote! { [#( #words ),*] }
+            },
+            _ => {
+                let bits = positions.into_iter().fold(0u128, |bits, position| bits | (1 << position));
+                Literal::u128_unsuffixed(bits).into_token_stream()
+            },
+        }
+    }
+
+    fn empty(&self) -> TokenStream {
+        match self {
+            Self::Words(len, krate) => quote! { #krate::words::empty::<#len>() },
+            _ => quote! { 0 },
+        }
+    }
+
+    fn union(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::union(#a, #b) },
+            _ => quote! { (#a | #b) },
+        }
+    }
+
+    fn intersection(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::intersection(#a, #b) },
+            _ => quote! { (#a & #b) },
+        }
+    }
+
+    fn difference(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::difference(#a, #b) },
+            _ => quote! { (#a & !#b) },
+        }
+    }
+
+    fn union_assign(&self, target: TokenStream, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #target = #krate::words::union(#target, #bits); },
+            _ => quote! { #target |= #bits; },
+        }
+    }
+
+    fn intersection_assign(&self, target: TokenStream, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #target = #krate::words::intersection(#target, #bits); },
+            _ => quote! { #target &= #bits; },
+        }
+    }
+
+    fn difference_assign(&self, target: TokenStream, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #target = #krate::words::difference(#target, #bits); },
+            _ => quote! { #target &= !#bits; },
+        }
+    }
+
+    fn symmetric_difference(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::symmetric_difference(#a, #b) },
+            _ => quote! { (#a ^ #b) },
+        }
+    }
+
+    fn symmetric_difference_assign(&self, target: TokenStream, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! {
+                #target = #krate::words::symmetric_difference(#target, #bits);
+            },
+            _ => quote! { #target ^= #bits; },
+        }
+    }
+
+    fn is_empty(&self, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::is_empty(#bits) },
+            _ => quote! { (#bits == 0) },
+        }
+    }
+
+    fn eq(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::eq(#a, #b) },
+            _ => quote! { (#a == #b) },
+        }
+    }
+
+    fn count(&self, bits: TokenStream) -> TokenStream {
+        match self {
+            Self::Words(_, krate) => quote! { #krate::words::count_ones(#bits) },
+            _ => quote! { (#bits.count_ones() as usize) },
+        }
+    }
+}
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// The case convention used for item names.
+enum RenameRule { None, Lowercase, SnakeCase, KebabCase, ScreamingSnakeCase }
+
+impl RenameRule {
+    fn try_from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "snake_case" => Ok(Self::SnakeCase),
+            "kebab-case" => Ok(Self::KebabCase),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "verbatim" => Ok(Self::None),
+            _ => Err(Error::new(
+                lit.span(),
+                "Expected one of `snake_case`, `kebab-case`, `SCREAMING_SNAKE_CASE` or \
+                `verbatim` as flagnum naming convention",
+            )),
+        }
+    }
+
+    fn try_from_accessor_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "snake_case" => Ok(Self::SnakeCase),
+            "lowercase" => Ok(Self::Lowercase),
+            _ => Err(Error::new(
+                lit.span(),
+                "Expected one of `snake_case` or `lowercase` as flagnum accessor naming convention",
+            )),
+        }
+    }
+
+    fn apply(&self, name: &str) -> String {
+        match self {
+            Self::None => name.into(),
+            Self::Lowercase => split_words(name).concat().to_lowercase(),
+            Self::SnakeCase => split_words(name).join("_").to_lowercase(),
+            Self::KebabCase => split_words(name).join("-").to_lowercase(),
+            Self::ScreamingSnakeCase => split_words(name).join("_").to_uppercase(),
+        }
+    }
+}
+
+/// Convert a `SCREAMING_SNAKE_CASE` group name into an `UpperCamelCase` variant name.
+fn upper_camel_case(name: &str) -> String {
+    split_words(name)
+        .into_iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|c| c.to_uppercase().to_string()).unwrap_or_default();
+            first + &chars.as_str().to_lowercase()
+        })
+        .collect()
+}
+
+/// Split an identifier into words at underscores and case changes.
+///
+/// Runs of uppercase letters are kept together, so `HTTPServer` becomes `HTTP` and
+/// `Server`.
+fn split_words(name: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = name.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (index, &(offset, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if start < offset {
+                words.push(&name[start..offset]);
+            }
+            start = offset + 1;
+            continue;
+        }
+        let Some(&(_, previous)) = index.checked_sub(1).and_then(|index| chars.get(index)) else {
+            continue;
+        };
+        let next = chars.get(index + 1).map(|&(_, next)| next);
+        let is_boundary = c.is_uppercase() && (
+            previous.is_lowercase()
+            || previous.is_ascii_digit()
+            || (previous.is_uppercase() && next.is_some_and(char::is_lowercase))
+        );
+        if is_boundary && start < offset {
+            words.push(&name[start..offset]);
+            start = offset;
+        }
+    }
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+    words
+}
+
+const MAX_POSITION: usize = u16::MAX as usize;
+
+/// Assign bit positions to variants.
+///
+/// Variants without an explicit `#[flagnum(bit = N)]` position use the position
+/// following the previous variant.
+fn variant_positions(body: &FlagnumEnum) -> syn::Result<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut used = HashMap::new();
+    let mut next = 0;
+    for (variant, bit) in body.item_enum.variants.iter().zip(&body.bits) {
+        let (position, span) = match bit {
+            Some(bit) => (bit.base10_parse::<usize>()?, bit.span()),
+            None => (next, variant.ident.span()),
+        };
+        if position > MAX_POSITION {
+            return Err(Error::new(
+                span,
+                format!("flagnum only supports bit positions up to {MAX_POSITION}"),
+            ));
+        }
+        if let Some(previous) = used.insert(position, &variant.ident) {
+            return Err(Error::new(
+                span,
+                format!("Bit position {position} is already used by variant `{previous}`"),
+            ));
+        }
+        positions.push(position);
+        next = position + 1;
+    }
+    Ok(positions)
+}
+
+/// Evaluate the members of all declared groups as sorted bit positions, and verify the
+/// declared group constraints.
+///
+/// Groups without an expression contain the variants tagged with `#[groups(...)]`.
+fn evaluate_groups(
+    decl: &FlagnumDecl,
+    body: &FlagnumEnum,
+    positions: &[usize],
+) -> syn::Result<Vec<Vec<usize>>> {
+    let mut evaluator = GroupEvaluator {
+        decl,
+        body,
+        positions,
+        states: vec![GroupState::Pending; decl.groups.len()],
+    };
+    let group_positions = (0..decl.groups.len())
+        .map(|index| Ok(evaluator.group(index)?.into_iter().collect()))
+        .collect::<syn::Result<_>>()?;
+    for constraint in &decl.constraints {
+        evaluator.check_constraint(constraint)?;
+    }
+    Ok(group_positions)
+}
+
+#[derive(Clone)]
+enum GroupState {
+    Pending,
+    Evaluating,
+    Done(BTreeSet<usize>),
+}
+
+struct GroupEvaluator<'a> {
+    decl: &'a FlagnumDecl,
+    body: &'a FlagnumEnum,
+    positions: &'a [usize],
+    states: Vec<GroupState>,
+}
+
+impl GroupEvaluator<'_> {
+    fn group(&mut self, index: usize) -> syn::Result<BTreeSet<usize>> {
+        if let GroupState::Done(members) = &self.states[index] {
+            return Ok(members.clone());
+        }
+        self.states[index] = GroupState::Evaluating;
+        let group = &self.decl.groups[index].value;
+        let members = match &group.expr {
+            Some(expr) => self.expr(expr)?,
+            None => self.body.grouped
+                .get(&group.name)
+                .map(|members| members.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .map(|member| self.variant_position(member).unwrap())
+                .collect(),
+        };
+        self.states[index] = GroupState::Done(members.clone());
+        Ok(members)
+    }
+
+    fn expr(&mut self, expr: &Expr) -> syn::Result<BTreeSet<usize>> {
+        match expr {
+            Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) => {
+                self.expr(expr)
+            },
+            Expr::Unary(ExprUnary { op: UnOp::Not(_), expr, .. }) => {
+                let members = self.expr(expr)?;
+                Ok(self.full().difference(&members).copied().collect())
+            },
+            Expr::Binary(ExprBinary { left, op, right, .. }) => {
+                let left = self.expr(left)?;
+                let right = self.expr(right)?;
+                match op {
+                    BinOp::BitOr(_) => Ok(left.union(&right).copied().collect()),
+                    BinOp::BitAnd(_) => Ok(left.intersection(&right).copied().collect()),
+                    BinOp::Sub(_) => Ok(left.difference(&right).copied().collect()),
+                    BinOp::BitXor(_) => Ok(left.symmetric_difference(&right).copied().collect()),
+                    _ => Err(Error::new_spanned(
+                        op,
+                        "Expected one of `|`, `&`, `-` or `^` in flagnum group expression",
+                    )),
+                }
+            },
+            Expr::Path(ExprPath { path, qself: None, .. }) if path.get_ident().is_some() => {
+                self.name(path.get_ident().unwrap())
+            },
+            _ => Err(Error::new_spanned(
+                expr,
+                "Expected a group or item name, `FULL`, `EMPTY`, `!`, one of `|`, `&`, `-` or \
+                `^`, or parentheses in flagnum group expression",
+            )),
+        }
+    }
+
+    fn name(&mut self, ident: &Ident) -> syn::Result<BTreeSet<usize>> {
+        let group = self.decl.groups.iter().position(|group| group.value.name == *ident);
+        if let Some(index) = group {
+            if let GroupState::Evaluating = self.states[index] {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("Group `{ident}` is defined in terms of itself"),
+                ));
+            }
+            self.group(index)
+        } else if let Some(position) = self.variant_position(ident) {
+            Ok(BTreeSet::from([position]))
+        } else if ident == "FULL" {
+            Ok(self.full())
+        } else if ident == "EMPTY" {
+            Ok(BTreeSet::new())
+        } else {
+            Err(Error::new(
+                ident.span(),
+                format!("Unknown group or item `{ident}` in flagnum group expression"),
+            ))
+        }
+    }
+
+    fn variant_position(&self, ident: &Ident) -> Option<usize> {
+        let index = self.body.item_enum.variants
+            .iter()
+            .position(|variant| variant.ident == *ident)?;
+        Some(self.positions[index])
+    }
+
+    fn full(&self) -> BTreeSet<usize> {
+        self.positions.iter().copied().collect()
+    }
+
+    fn check_constraint(&mut self, constraint: &GroupConstraint) -> syn::Result<()> {
+        let GroupConstraint { kind, span, groups } = constraint;
+        let (check_disjoint, check_covers) = match kind {
+            ConstraintKind::Partition => (true, true),
+            ConstraintKind::Disjoint => (true, false),
+            ConstraintKind::Covers => (false, true),
+        };
+        let mut covered: Vec<(&Expr, BTreeSet<usize>)> = Vec::new();
+        for group in groups {
+            let members = self.expr(group)?;
+            if check_disjoint {
+                for (previous, previous_members) in &covered {
+                    let shared: Vec<usize> = members.intersection(previous_members).copied().collect();
+                    if !shared.is_empty() {
+                        return Err(Error::new_spanned(
+                            group,
+                            format!(
+                                "{} in both `{}` and `{}`",
+                                self.describe_variants(&shared),
+                                previous.to_token_stream(),
+                                group.to_token_stream(),
+                            ),
+                        ));
+                    }
+                }
+            }
+            covered.push((group, members));
+        }
+        if check_covers {
+            let uncovered: Vec<usize> = self.positions
+                .iter()
+                .copied()
+                .filter(|position| !covered.iter().any(|(_, members)| members.contains(position)))
+                .collect();
+            if !uncovered.is_empty() {
+                return Err(Error::new(
+                    *span,
+                    format!(
+                        "{} in none of the groups of this `{}` constraint",
+                        self.describe_variants(&uncovered),
+                        kind.name(),
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Describe the variants at the given positions as the subject of an error message.
+    fn describe_variants(&self, positions: &[usize]) -> String {
+        let names: Vec<String> = self.body.item_enum.variants
+            .iter()
+            .zip(self.positions)
+            .filter(|(_, position)| positions.contains(position))
+            .map(|(variant, _)| format!("`{}`", variant.ident))
+            .collect();
+        if names.len() == 1 {
+            format!("Variant {} is", names[0])
+        } else {
+            format!("Variants {} are", names.join(", "))
+        }
+    }
 }
 
-fn set_variant_offset(variant: &mut Variant, offset: usize) {
+/// The `#[cfg(...)]` attributes that have to be repeated wherever a group is referenced.
+fn cfg_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("cfg"))
+}
+
+/// The automatically implemented traits required by [`flagnum::Flag`] and
+/// [`flagnum::Flags`].
+const REQUIRED_DERIVES: &[&str] = &[
+    "Debug", "Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash",
+];
+
+/// The automatically implemented traits that can be skipped on the item type.
+const ITEM_DERIVES: &[&str] = &[
+    "Debug", "Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash",
+    "Display", "FromStr", "Serialize", "Deserialize",
+];
+
+/// The automatically implemented traits that can be skipped on the set type.
+const SET_DERIVES: &[&str] = &[
+    "Debug", "Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash",
+    "Display", "FromStr", "Binary", "Octal", "LowerHex", "UpperHex", "Serialize", "Deserialize",
+];
+
+fn check_skipped_derives(skipped: &[Ident], known: &[&str], kind: &str) -> syn::Result<()> {
+    for name in skipped {
+        if !known.iter().any(|known| name == known) {
+            return Err(Error::new(
+                name.span(),
+                format!("`{name}` is not automatically implemented for the flagnum {kind} type"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The direct and indirect supertraits of one of the [`REQUIRED_DERIVES`].
+fn required_supertraits(name: &str) -> &'static [&'static str] {
+    match name {
+        "Copy" => &["Clone"],
+        "Eq" | "PartialOrd" => &["PartialEq"],
+        "Ord" => &["Eq", "PartialOrd", "PartialEq"],
+        _ => &[],
+    }
+}
+
+/// The skipped traits that are required by the `Flag` and `Flags` traits.
+fn required_skipped(skipped: &[Ident]) -> impl Iterator<Item = &Ident> {
+    skipped.iter().filter(|name| REQUIRED_DERIVES.iter().any(|required| name == required))
+}
+
+/// Build assertions that skipped traits required by the flagnum traits are still
+/// implemented, reporting missing implementations at the skipped trait name.
+fn build_required_impl_checks(ty: &Ident, skipped: &[Ident]) -> TokenStream {
+    required_skipped(skipped).map(|name| {
+        let path = required_trait_path(&name.to_string(), name.span());
+        quote_spanned! { name.span()=>
+            const _: fn() = {
+                fn skipped_trait_needs_manual_impl<T: #path>() {}
+                skipped_trait_needs_manual_impl::<#ty>
+            };
+        }
+    }).collect()
+}
+
+/// The absolute path to one of the [`REQUIRED_DERIVES`].
+fn required_trait_path(name: &str, span: Span) -> TokenStream {
+    let module = match name {
+        "Debug" => "fmt",
+        "Clone" => "clone",
+        "Copy" => "marker",
+        "Hash" => "hash",
+        _ => "cmp",
+    };
+    let module = Ident::new(module, span);
+    let name = Ident::new(name, span);
+    quote_spanned! { span=> ::core::#module::#name }
+}
+
+/// Verify that the single item constants don't conflict with other set constants.
+fn check_item_constants(decl: &FlagnumDecl, body: &FlagnumEnum) -> syn::Result<()> {
+    let mut used: HashMap<String, String> = ["EMPTY", "FULL", "ITEMS", "GROUPS"]
+        .into_iter()
+        .map(|name| (name.to_string(), format!("`{name}`")))
+        .collect();
+    for group in &decl.groups {
+        let name = group.value.name.unraw().to_string();
+        used.insert(name.clone(), format!("group `{name}`"));
+    }
+    for variant in &body.item_enum.variants {
+        let constant = RenameRule::ScreamingSnakeCase.apply(&variant.ident.unraw().to_string());
+        let owner = format!("variant `{}`", variant.ident);
+        if let Some(previous) = used.insert(constant.clone(), owner) {
+            return Err(Error::new(
+                variant.ident.span(),
+                format!("Item constant `{constant}` conflicts with {previous}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Verify that the exposed groups map to distinct variants of the group enum.
+fn check_group_enum_variants(decl: &FlagnumDecl) -> syn::Result<()> {
+    if decl.group_enum.is_none() {
+        return Ok(());
+    }
+    let mut used: HashMap<String, String> = HashMap::new();
+    for group in decl.groups.iter().filter(|group| group.vis.is_some()) {
+        let name = group.value.name.unraw().to_string();
+        let variant = upper_camel_case(&name);
+        if let Some(previous) = used.insert(variant.clone(), name) {
+            return Err(Error::new(
+                group.value.name.span(),
+                format!("Group enum variant `{variant}` conflicts with group `{previous}`"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The names of the getter, setter and toggle methods of an item.
+///
+/// Getters for items named like keywords use raw identifiers.
+fn item_accessor_names(variant: &Ident, rule: &RenameRule) -> [Ident; 3] {
+    let span = variant.span();
+    let method = rule.apply(&variant.unraw().to_string());
+    let getter = parse_str::<Ident>(&method)
+        .map(|ident| Ident::new(&ident.to_string(), span))
+        .unwrap_or_else(|_| Ident::new_raw(&method, span));
+    let setter = format_ident!("set_{method}", span = span);
+    let toggle = format_ident!("toggle_{method}", span = span);
+    [getter, setter, toggle]
+}
+
+/// The inherent methods of every set type.
+const SET_METHODS: &[&str] = &[
+    "from_item", "from_items", "from_sets", "from_bits", "from_bits_truncate",
+    "from_bits_retain", "bits", "len", "is_empty", "is_full", "missing", "invert",
+    "const_contains", "const_has_overlap", "const_is_subset_of", "const_is_superset_of",
+    "const_overlap", "const_with", "const_without", "const_is_strict_subset_of",
+    "const_is_disjoint", "const_symmetric_difference", "const_insert", "const_remove",
+    "const_keep", "const_toggle", "__item_bits",
+];
+
+/// The trait methods of every set type that inherent methods would shadow.
+const SET_TRAIT_METHODS: &[&str] = &[
+    "contains", "has_overlap", "is_subset_of", "is_strict_subset_of", "is_superset_of",
+    "is_disjoint", "symmetric_difference", "toggle", "overlap", "with", "without",
+    "insert", "remove", "keep", "retain", "retained", "display_grouped",
+    "clone", "eq", "ne", "cmp", "partial_cmp", "max", "min", "hash", "fmt", "from", "into",
+    "from_iter", "into_iter", "extend", "default", "to_string",
+];
+
+/// The names of the `contains_all_`, `contains_any_`, `_part` and `clear_` methods of
+/// a group.
+fn group_method_names(group: &Ident) -> [Ident; 4] {
+    let span = group.span();
+    let method_name = group.unraw().to_string().to_lowercase();
+    [
+        format_ident!("contains_all_{method_name}", span = span),
+        format_ident!("contains_any_{method_name}", span = span),
+        format_ident!("{method_name}_part", span = span),
+        format_ident!("clear_{method_name}", span = span),
+    ]
+}
+
+/// Verify that the group methods and item accessor methods don't conflict with each
+/// other, with other inherent methods of the set type or with the trait methods they
+/// would shadow.
+fn check_set_methods(
+    decl: &FlagnumDecl,
+    body: &FlagnumEnum,
+    item_accessors: Option<&RenameRule>,
+) -> syn::Result<()> {
+    if !decl.group_methods && item_accessors.is_none() {
+        return Ok(());
+    }
+    let mut used: HashMap<String, String> = HashMap::new();
+    let mut fixed = SET_METHODS.to_vec();
+    if decl.groups.iter().any(|group| group.value.exclusive) {
+        fixed.extend(["set_exclusive", "selected_in", "validate_exclusive"]);
+    }
+    if decl.group_enum.is_some() {
+        fixed.extend(["complete_groups", "touched_groups"]);
+    }
+    for name in fixed {
+        used.insert(name.to_string(), format!("the method `{name}`"));
+    }
+    for name in SET_TRAIT_METHODS {
+        used.insert(name.to_string(), format!("the trait method `{name}`"));
+    }
+    if decl.group_methods {
+        for group in &decl.groups {
+            let group = &group.value.name;
+            for method in group_method_names(group) {
+                let method = method.to_string();
+                let owner = format!("the method `{method}` of group `{group}`");
+                if let Some(previous) = used.insert(method.clone(), owner) {
+                    return Err(Error::new(
+                        group.span(),
+                        format!("Group method `{method}` conflicts with {previous}"),
+                    ));
+                }
+            }
+        }
+    }
+    let Some(rule) = item_accessors else {
+        return Ok(());
+    };
+    for variant in &body.item_enum.variants {
+        for method in item_accessor_names(&variant.ident, rule) {
+            let method = method.unraw().to_string();
+            let owner = format!("the accessor `{method}` of variant `{}`", variant.ident);
+            if let Some(previous) = used.insert(method.clone(), owner) {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    format!("Item accessor `{method}` conflicts with {previous}"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn variant_names(body: &FlagnumEnum, rename_rule: &RenameRule) -> syn::Result<Vec<String>> {
+    let mut names = Vec::new();
+    let mut used = HashMap::new();
+    for (variant, explicit) in body.item_enum.variants.iter().zip(&body.names) {
+        let (name, span) = match explicit {
+            Some(lit) if lit.value().is_empty() => {
+                return Err(Error::new(lit.span(), "Item names must not be empty"));
+            },
+            Some(lit) => (lit.value(), lit.span()),
+            None => (rename_rule.apply(&variant.ident.unraw().to_string()), variant.ident.span()),
+        };
+        if let Some(previous) = used.insert(name.clone(), &variant.ident) {
+            return Err(Error::new(
+                span,
+                format!("Item name `{name}` is already used by variant `{previous}`"),
+            ));
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
+fn set_variant_discriminant(variant: &mut Variant, value: Literal) {
     let span = variant.span();
     variant.discriminant = Some((
         Eq { spans: [span] },
         Expr::Lit(ExprLit {
             attrs: Vec::new(),
-            lit: Lit::new(Literal::u128_unsuffixed(1 << offset)),
+            lit: Lit::new(value),
         }),
     ));
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(attr: &str, item: &str) -> String {
+        let decl = parse_str(attr).unwrap();
+        let body = parse_str(item).unwrap();
+        match FlagnumContext::new(decl, body) {
+            Ok(_) => panic!("expected an error for `{attr}`"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn disjoint_overlap() {
+        assert_eq!(
+            error(
+                "Days, groups(WEEKEND, EDGES = Monday | Sunday), disjoint(WEEKEND, EDGES)",
+                "enum Day { Monday, Tuesday, #[groups(WEEKEND)] Saturday, #[groups(WEEKEND)] Sunday }",
+            ),
+            "Variant `Sunday` is in both `WEEKEND` and `EDGES`",
+        );
+        assert_eq!(
+            error(
+                "Days, groups(LOW = A | B | C, HIGH = B | C), disjoint(LOW, HIGH)",
+                "enum Item { A, B, C }",
+            ),
+            "Variants `B`, `C` are in both `LOW` and `HIGH`",
+        );
+    }
+
+    #[test]
+    fn uncovered() {
+        assert_eq!(
+            error(
+                "Days, groups(WEEKEND, EDGES = Monday), covers(WEEKEND, EDGES)",
+                "enum Day { Monday, Tuesday, #[groups(WEEKEND)] Saturday }",
+            ),
+            "Variant `Tuesday` is in none of the groups of this `covers` constraint",
+        );
+        assert_eq!(
+            error(
+                "Days, groups(WEEKEND, WORKDAYS = Monday), partition(WEEKEND, WORKDAYS)",
+                "enum Day { Monday, Tuesday, Wednesday, #[groups(WEEKEND)] Saturday }",
+            ),
+            "Variants `Tuesday`, `Wednesday` are in none of the groups of this `partition` \
+            constraint",
+        );
+    }
+}
@@ -17,8 +17,8 @@ pub fn flag(attr: TokenStream, item: TokenStream) -> TokenStream {
     builder::FlagnumContext::new(
         parse_macro_input!(attr),
         parse_macro_input!(item),
-    ).and_then(|ctx| {
-        Ok(ctx.build())
+    ).map(|ctx| {
+        ctx.build()
     }).unwrap_or_else(|error| {
         error.to_compile_error()
     }).into()
@@ -1,18 +1,50 @@
 use std::collections::HashMap;
 
+use proc_macro2::Span;
+use quote::ToTokens;
 use syn::punctuated::Punctuated;
-use syn::{Attribute, parenthesized, Token, Ident, ItemEnum, Fields, Error, parse2, Visibility};
+use syn::{Attribute, parenthesized, Token, Ident, ItemEnum, Fields, Error, parse2, Visibility, LitInt, LitStr, Expr, Path};
 use syn::parse::{Parse, ParseStream};
 
 
 mod kw {
     use syn::custom_keyword;
     custom_keyword!(groups);
+    custom_keyword!(repr);
+    custom_keyword!(bit);
+    custom_keyword!(name);
+    custom_keyword!(keep_discriminants);
+    custom_keyword!(retain_unknown_bits);
+    custom_keyword!(rename_all);
+    custom_keyword!(separator);
+    custom_keyword!(group_enum);
+    custom_keyword!(partition);
+    custom_keyword!(disjoint);
+    custom_keyword!(covers);
+    custom_keyword!(exclusive);
+    custom_keyword!(group_methods);
+    custom_keyword!(item_const_fns);
+    custom_keyword!(item_accessors);
+    custom_keyword!(skip_item_derives);
+    custom_keyword!(skip_set_derives);
 }
 
 pub struct FlagnumDecl {
     pub set: WithAttrs<Ident>,
-    pub groups: Vec<WithAttrs<Ident>>,
+    pub groups: Vec<WithAttrs<GroupDecl>>,
+    pub repr: Option<Ident>,
+    pub keep_discriminants: bool,
+    pub retain_unknown_bits: bool,
+    pub rename_all: Option<LitStr>,
+    pub separator: Option<LitStr>,
+    pub group_enum: Option<GroupEnumDecl>,
+    pub constraints: Vec<GroupConstraint>,
+    pub group_methods: bool,
+    pub item_const_fns: bool,
+    pub item_accessors: Option<ItemAccessorsDecl>,
+    pub skip_item_derives: Vec<Ident>,
+    pub skip_set_derives: Vec<Ident>,
+    pub krate: Option<Path>,
 }
 
 impl Parse for FlagnumDecl {
@@ -20,6 +52,19 @@ impl Parse for FlagnumDecl {
         let set = input.parse()?;
         let mut groups = Vec::new();
         let mut groups_initialized = false;
+        let mut repr = None;
+        let mut keep_discriminants = false;
+        let mut retain_unknown_bits = false;
+        let mut rename_all = None;
+        let mut separator = None;
+        let mut group_enum = None;
+        let mut constraints = Vec::new();
+        let mut group_methods = false;
+        let mut item_const_fns = false;
+        let mut item_accessors = None;
+        let mut skip_item_derives = None;
+        let mut skip_set_derives = None;
+        let mut krate = None;
         while input.call(try_parse_comma_continuation)? {
             if let Some(groups_decl) = input.call(try_parse_groups_decl)? {
                 if groups_initialized {
@@ -31,38 +76,197 @@ impl Parse for FlagnumDecl {
                 }
                 groups = groups_decl;
                 continue;
+            } else if input.peek(kw::repr) {
+                parse_assignment::<kw::repr, _>(input, &mut repr)?;
+                continue;
+            } else if input.peek(kw::keep_discriminants) {
+                parse_switch::<kw::keep_discriminants>(input, &mut keep_discriminants)?;
+                continue;
+            } else if input.peek(kw::retain_unknown_bits) {
+                parse_switch::<kw::retain_unknown_bits>(input, &mut retain_unknown_bits)?;
+                continue;
+            } else if input.peek(kw::rename_all) {
+                parse_assignment::<kw::rename_all, _>(input, &mut rename_all)?;
+                continue;
+            } else if input.peek(kw::separator) {
+                parse_assignment::<kw::separator, _>(input, &mut separator)?;
+                continue;
+            } else if input.peek(kw::group_enum) {
+                let keyword: kw::group_enum = input.parse()?;
+                if group_enum.is_some() {
+                    return Err(Error::new_spanned(
+                        keyword,
+                        "`group_enum` has already been declared for this flagnum enum",
+                    ));
+                }
+                group_enum = Some(input.parse()?);
+                continue;
+            } else if input.peek(kw::group_methods) {
+                parse_switch::<kw::group_methods>(input, &mut group_methods)?;
+                continue;
+            } else if input.peek(kw::item_const_fns) {
+                parse_switch::<kw::item_const_fns>(input, &mut item_const_fns)?;
+                continue;
+            } else if input.peek(kw::item_accessors) {
+                let decl: ItemAccessorsDecl = input.parse()?;
+                if item_accessors.is_some() {
+                    return Err(Error::new_spanned(
+                        decl.keyword,
+                        "`item_accessors` has already been declared for this flagnum enum",
+                    ));
+                }
+                item_accessors = Some(decl);
+                continue;
+            } else if input.peek(kw::skip_item_derives) {
+                parse_list::<kw::skip_item_derives, _>(input, &mut skip_item_derives)?;
+                continue;
+            } else if input.peek(kw::skip_set_derives) {
+                parse_list::<kw::skip_set_derives, _>(input, &mut skip_set_derives)?;
+                continue;
+            } else if input.peek(Token![crate]) {
+                parse_assignment::<Token![crate], _>(input, &mut krate)?;
+                continue;
+            } else if let Some(constraint) = input.call(try_parse_group_constraint)? {
+                constraints.push(constraint);
+                continue;
             } else {
-                let error_msg = if groups_initialized {
-                    "Expected no further arguments"
-                } else {
-                    "Expected a `groups` declaration or the end of arguments"
-                };
-                return Err(input.error(error_msg));
+                return Err(input.error(
+                    "Expected a known flagnum argument or the end of arguments",
+                ));
             }
         }
         Ok(Self {
             set,
             groups,
+            repr,
+            keep_discriminants,
+            retain_unknown_bits,
+            rename_all,
+            separator,
+            group_enum,
+            constraints,
+            group_methods,
+            item_const_fns,
+            item_accessors,
+            skip_item_derives: skip_item_derives.unwrap_or_default(),
+            skip_set_derives: skip_set_derives.unwrap_or_default(),
+            krate,
         })
     }
 }
 
+pub struct ItemAccessorsDecl {
+    keyword: kw::item_accessors,
+    pub case: Option<LitStr>,
+}
+
+impl Parse for ItemAccessorsDecl {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let keyword = input.parse()?;
+        let case = if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { keyword, case })
+    }
+}
+
+pub struct GroupEnumDecl {
+    pub item: Ident,
+    pub set: Ident,
+}
+
+impl Parse for GroupEnumDecl {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let arguments;
+        parenthesized!(arguments in input);
+        let item = arguments.parse()?;
+        let _: Token![,] = arguments.parse()?;
+        let set = arguments.parse()?;
+        let _: Option<Token![,]> = arguments.parse()?;
+        if !arguments.is_empty() {
+            return Err(arguments.error("Expected only a group enum and a group set name"));
+        }
+        Ok(Self { item, set })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ConstraintKind {
+    Partition,
+    Disjoint,
+    Covers,
+}
+
+impl ConstraintKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Partition => "partition",
+            Self::Disjoint => "disjoint",
+            Self::Covers => "covers",
+        }
+    }
+}
+
+pub struct GroupConstraint {
+    pub kind: ConstraintKind,
+    pub span: Span,
+    pub groups: Vec<Expr>,
+}
+
+fn try_parse_group_constraint(input: ParseStream<'_>) -> syn::Result<Option<GroupConstraint>> {
+    let (kind, span) = if input.peek(kw::partition) {
+        (ConstraintKind::Partition, input.parse::<kw::partition>()?.span)
+    } else if input.peek(kw::disjoint) {
+        (ConstraintKind::Disjoint, input.parse::<kw::disjoint>()?.span)
+    } else if input.peek(kw::covers) {
+        (ConstraintKind::Covers, input.parse::<kw::covers>()?.span)
+    } else {
+        return Ok(None);
+    };
+    let groups = input.call(parse_arguments)?;
+    Ok(Some(GroupConstraint { kind, span, groups }))
+}
+
+pub struct GroupDecl {
+    pub exclusive: bool,
+    pub name: Ident,
+    pub expr: Option<Expr>,
+}
+
+impl Parse for GroupDecl {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let exclusive = input.peek(kw::exclusive) && input.peek2(Ident);
+        if exclusive {
+            let _: kw::exclusive = input.parse()?;
+        }
+        let name = input.parse()?;
+        let expr = if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { exclusive, name, expr })
+    }
+}
+
 pub struct FlagnumEnum {
     pub item_enum: ItemEnum,
     pub grouped: HashMap<Ident, Vec<Ident>>,
+    pub bits: Vec<Option<LitInt>>,
+    pub names: Vec<Option<LitStr>>,
 }
 
 impl Parse for FlagnumEnum {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
         let mut item_enum: ItemEnum = input.parse()?;
         let mut grouped: HashMap<Ident, Vec<Ident>> = HashMap::new();
+        let mut bits = Vec::new();
+        let mut names = Vec::new();
         for variant in &mut item_enum.variants {
-            if let Some((eq, _)) = variant.discriminant {
-                return Err(Error::new(
-                    eq.spans[0],
-                    "flagnum enums cannot have explicit discriminant values",
-                ));
-            }
             match variant.fields {
                 Fields::Unit => (),
                 _ => {
@@ -73,25 +277,76 @@ impl Parse for FlagnumEnum {
                 },
             }
             let mut retained_attrs = Vec::new();
+            let mut bit = None;
+            let mut name = None;
             for attr in &variant.attrs {
                 if attr.path.is_ident("groups") {
                     let variant_groups: Arguments<Ident> = parse2(attr.tokens.clone())?;
                     for group in variant_groups.values {
                         grouped.entry(group).or_default().push(variant.ident.clone());
                     }
+                } else if attr.path.is_ident("flagnum") {
+                    let variant_args: Arguments<VariantArg> = parse2(attr.tokens.clone())?;
+                    for arg in variant_args.values {
+                        match arg {
+                            VariantArg::Bit(position) => {
+                                if bit.is_some() {
+                                    return Err(Error::new(
+                                        position.span(),
+                                        "A bit position has already been declared for this variant",
+                                    ));
+                                }
+                                bit = Some(position);
+                            },
+                            VariantArg::Name(lit) => {
+                                if name.is_some() {
+                                    return Err(Error::new(
+                                        lit.span(),
+                                        "A name has already been declared for this variant",
+                                    ));
+                                }
+                                name = Some(lit);
+                            },
+                        }
+                    }
                 } else {
                     retained_attrs.push(attr.clone());
                 }
             };
             variant.attrs = retained_attrs;
+            bits.push(bit);
+            names.push(name);
         }
         Ok(Self {
             item_enum,
             grouped,
+            bits,
+            names,
         })
     }
 }
 
+enum VariantArg {
+    Bit(LitInt),
+    Name(LitStr),
+}
+
+impl Parse for VariantArg {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.peek(kw::bit) {
+            let _: kw::bit = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self::Bit(input.parse()?))
+        } else if input.peek(kw::name) {
+            let _: kw::name = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self::Name(input.parse()?))
+        } else {
+            Err(input.error("Expected a `bit = <position>` or `name = \"...\"` argument"))
+        }
+    }
+}
+
 fn try_parse_comma_continuation(input: ParseStream<'_>) -> syn::Result<bool> {
     if input.peek(Token![,]) {
         let _: Token![,] = input.parse()?;
@@ -124,6 +379,63 @@ where
     Ok(Some(groups))
 }
 
+fn parse_switch<K>(input: ParseStream<'_>, switch: &mut bool) -> syn::Result<()>
+where
+    K: Parse + ToTokens,
+{
+    let keyword: K = input.parse()?;
+    if *switch {
+        return Err(Error::new_spanned(
+            &keyword,
+            format!(
+                "`{}` has already been declared for this flagnum enum",
+                keyword.to_token_stream(),
+            ),
+        ));
+    }
+    *switch = true;
+    Ok(())
+}
+
+fn parse_assignment<K, V>(input: ParseStream<'_>, value: &mut Option<V>) -> syn::Result<()>
+where
+    K: Parse + ToTokens,
+    V: Parse,
+{
+    let keyword: K = input.parse()?;
+    if value.is_some() {
+        return Err(Error::new_spanned(
+            &keyword,
+            format!(
+                "`{}` has already been declared for this flagnum enum",
+                keyword.to_token_stream(),
+            ),
+        ));
+    }
+    let _: Token![=] = input.parse()?;
+    *value = Some(input.parse()?);
+    Ok(())
+}
+
+fn parse_list<K, V>(input: ParseStream<'_>, values: &mut Option<Vec<V>>) -> syn::Result<()>
+where
+    K: Parse + ToTokens,
+    V: Parse,
+{
+    let keyword: K = input.parse()?;
+    if values.is_some() {
+        return Err(Error::new_spanned(
+            &keyword,
+            format!(
+                "`{}` has already been declared for this flagnum enum",
+                keyword.to_token_stream(),
+            ),
+        ));
+    }
+    *values = Some(input.call(parse_arguments)?);
+    Ok(())
+}
+
 struct Arguments<T> {
     values: Vec<T>,
 }
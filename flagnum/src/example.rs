@@ -16,4 +16,5 @@ pub use weekdays::*;
 fn weekdays_example() {
     assert!(Weekdays::default().is_empty());
     assert_eq!(Weekday::default(), Weekday::Monday);
+    assert_eq!(Weekdays::WORKDAYS.len(), 5);
 }
\ No newline at end of file
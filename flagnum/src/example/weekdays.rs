@@ -9,6 +9,7 @@ use flagnum::Flags;
         /// values that fall on the weekend.
         pub WEEKEND,
     ),
+    item_const_fns,
 )]
 #[derive(Default)]
 pub enum Weekday {
@@ -31,6 +32,9 @@ pub enum Weekday {
 
 /// The set type can be freely extended with inherent methods and traits.
 impl Weekdays {
+    /// Additional constants can be derived from groups with the inherent `const` functions.
+    pub const WORKDAYS: Self = Self::FULL.const_without(Self::WEEKEND);
+
     pub fn non_weekend_len(self) -> usize {
         self.without(Self::WEEKEND).len()
     }
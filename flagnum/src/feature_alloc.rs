@@ -0,0 +1,2 @@
+
+pub use alloc::vec::Vec;
@@ -3,11 +3,12 @@ pub use serde as dep;
 
 use crate::Flags;
 
-pub struct SetVisitor<T>(std::marker::PhantomData<fn() -> T>);
+pub struct SetVisitor<T>(core::marker::PhantomData<fn() -> T>);
 
 impl<T> SetVisitor<T> {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        SetVisitor(std::marker::PhantomData::default())
+        SetVisitor(core::marker::PhantomData)
     }
 }
 
@@ -18,7 +19,7 @@ where
 {
     type Value = T;
 
-    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "a sequence of set items")
     }
 
@@ -32,4 +33,79 @@ where
         }
         Ok(set)
     }
-}
\ No newline at end of file
+}
+
+/// The known items of a set, represented as a sequence.
+pub struct KnownItems<T>(pub T);
+
+impl<T> dep::Serialize for KnownItems<T>
+where
+    T: Flags,
+    T::Item: dep::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: dep::Serializer,
+    {
+        use dep::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in crate::Iter::new(self.0) {
+            seq.serialize_element(&item)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T> dep::Deserialize<'de> for KnownItems<T>
+where
+    T: Flags,
+    T::Item: dep::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: dep::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SetVisitor::new()).map(KnownItems)
+    }
+}
+
+/// Visits a set retaining unknown bits, represented as a tuple of the known items and
+/// the raw unknown bits.
+///
+/// The fixed tuple layout doesn't rely on the format describing its own types, so it
+/// also works with formats like bincode or postcard.
+pub struct RetainingSetVisitor<T>(core::marker::PhantomData<fn() -> T>);
+
+impl<T> RetainingSetVisitor<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        RetainingSetVisitor(core::marker::PhantomData)
+    }
+}
+
+impl<'de, T> dep::de::Visitor<'de> for RetainingSetVisitor<T>
+where
+    T: Flags,
+    T::Item: dep::Deserialize<'de>,
+    T::Bits: dep::Deserialize<'de>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a tuple of set items and unknown bits")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: dep::de::SeqAccess<'de>,
+    {
+        let KnownItems(mut set) = seq
+            .next_element::<KnownItems<T>>()?
+            .ok_or_else(|| dep::de::Error::invalid_length(0, &self))?;
+        let unknown = seq
+            .next_element::<T::Bits>()?
+            .ok_or_else(|| dep::de::Error::invalid_length(1, &self))?;
+        set.insert(T::from_bits_retain(unknown));
+        Ok(set)
+    }
+}
@@ -1,3 +1,4 @@
+#![no_std]
 #![allow(unused_parens)]
 #![warn(elided_lifetimes_in_paths)]
 #![forbid(unused_must_use)]
@@ -12,11 +13,11 @@
 //!
 //! These traits are automatically implemented for both enum and set types:
 //!
-//! * [`Debug`](std::fmt::Debug) with a sequence based implementation for sets
+//! * [`Debug`](core::fmt::Debug) with a sequence based implementation for sets
 //! * [`Clone`] and [`Copy`]
 //! * [`PartialEq`] and [`Eq`]
 //! * [`PartialOrd`] and [`Ord`]
-//! * [`Hash`](std::hash::Hash)
+//! * [`Hash`](core::hash::Hash)
 //!
 //! These traits are also automatically implemented for set types:
 //!
@@ -24,21 +25,696 @@
 //! * [`FromIterator`] for anything that can be turned into a set
 //! * [`IntoIterator`]
 //! * [`Extend`] for iterators over anything that can be turned into a set
+//!
+//! # Operators
+//!
+//! Set types implement these operators, accepting anything that can be turned into
+//! a set on the right-hand side:
+//!
+//! * `|` and `|=` as [`Flags::with`] and [`Flags::insert`]
+//! * `&` and `&=` as [`Flags::overlap`] and [`Flags::keep`]
+//! * `-` and `-=` as [`Flags::without`] and [`Flags::remove`]
+//! * `^` and `^=` as [`Flags::symmetric_difference`] and [`Flags::toggle`]
+//! * `!` as [`Flags::missing`]
+//!
+//! The binary operators and `!` are also implemented for the item types, producing
+//! sets:
+//!
+//! ```rust
+//! # use flagnum::Flags;
+//! #[flagnum::flag(Colors)]
+//! enum Color { Red, Green, Blue }
+//!
+//! let colors = Color::Red | Color::Green;
+//! assert_eq!(colors, Colors::from([Color::Red, Color::Green]));
+//! assert_eq!(!colors, Color::Blue.into());
+//! assert_eq!(colors - Color::Red, Color::Green.into());
+//! ```
+//!
+//! Operators can't be used in `const` contexts, but the inherent `const` variants of
+//! the corresponding methods can:
+//!
+//! ```rust
+//! # use flagnum::Flags;
+//! #[flagnum::flag(Colors)]
+//! enum Color { Red, Green, Blue }
+//!
+//! const NOT_RED: Colors = Colors::FULL.const_without(Colors::from_item(Color::Red));
+//! const _: () = assert!(NOT_RED.const_contains(Colors::from_item(Color::Blue)));
+//! assert_eq!(NOT_RED, Color::Green | Color::Blue);
+//! ```
+//!
+//! The `const` variants of methods accepting anything convertible into a set, such
+//! as `with`, `without` or `contains`, are prefixed with `const_` and take a concrete
+//! set. Inherent methods take precedence over trait methods, so giving them the same
+//! names would shadow the generic [`Flags`] methods and reject items as arguments.
+//! A `const` item using the trait method doesn't compile:
+//!
+//! ```compile_fail
+//! # use flagnum::Flags;
+//! # #[flagnum::flag(Weekdays, groups(WEEKEND))]
+//! # enum Weekday { Monday, #[groups(WEEKEND)] Sunday }
+//! const WORKDAYS: Weekdays = Weekdays::FULL.without(Weekdays::WEEKEND);
+//! ```
+//!
+//! Instead, it is spelled with the `const_` variant:
+//!
+//! ```rust
+//! # use flagnum::Flags;
+//! #[flagnum::flag(Weekdays, groups(WEEKEND))]
+//! enum Weekday {
+//!     Monday,
+//!     #[groups(WEEKEND)]
+//!     Saturday,
+//!     #[groups(WEEKEND)]
+//!     Sunday,
+//! }
+//!
+//! const WORKDAYS: Weekdays = Weekdays::FULL.const_without(Weekdays::WEEKEND);
+//! assert_eq!(WORKDAYS, Weekdays::from_item(Weekday::Monday));
+//! ```
+//!
+//! Methods without such arguments, like `len` or `missing`, keep their names.
+//!
+//! # Raw Bits
+//!
+//! The raw bits of a set are available via [`Flags::bits`], and sets can be
+//! reconstructed with [`Flags::from_bits`] or [`Flags::from_bits_truncate`]. The
+//! type of the raw bits is available as [`Flags::Bits`].
+//!
+//! Set types implement [`Binary`](core::fmt::Binary), [`Octal`](core::fmt::Octal),
+//! [`LowerHex`](core::fmt::LowerHex) and [`UpperHex`](core::fmt::UpperHex) by
+//! formatting their raw bits. The alternate `#` flag adds the radix prefix and
+//! zero-pads the digits to the full width of the representation:
+//!
+//! ```rust
+//! #[flagnum::flag(Colors)]
+//! enum Color { Red, Green, Blue }
+//!
+//! let colors = Colors::from([Color::Red, Color::Blue]);
+//! assert_eq!(format!("{colors:b}"), "101");
+//! assert_eq!(format!("{colors:#b}"), "0b00000101");
+//! assert_eq!(format!("{colors:#x}"), "0x05");
+//! ```
+//!
+//! # Crate Features
+//!
+//! flagnum is `#![no_std]` and the generated code only depends on `core`, so sets
+//! can be used on bare-metal targets with `default-features = false`.
+//!
+//! * `std` (default): Implements [`Error`](std::error::Error) for the error types.
+//!   Enables `alloc`.
+//! * `alloc`: Implements [`From`] for `Vec` on set types.
+//! * `serde`: Implements serde's `Serialize` and `Deserialize` on item and set types.
 
 /// Entry point for enum and set type code generation.
 ///
 /// See the general [flagnum] documentation for general usage information.
 ///
 /// See the [`example`](crate::example) module for an example of using this attribute.
+///
+/// # Arguments
+///
+/// The first argument is always the name of the set type, optionally preceded by
+/// attributes and a visibility. It can be followed by these arguments:
+///
+/// ## `groups(...)`
+///
+/// Declares the group constants available on the set type. Items are assigned to
+/// groups with a `#[groups(...)]` attribute on the variant.
+///
+/// Each group can be preceded by attributes and a visibility, which apply to the
+/// generated constant. Groups with a visibility are also listed in [`Flags::GROUPS`].
+///
+/// A group can also be defined by an expression over other groups and items, like
+/// `WORKDAYS = !WEEKEND`. Expressions support group and item names, `FULL`, `EMPTY`,
+/// the `|`, `&`, `-` and `^` operators, `!` for the complement and parentheses, and
+/// are evaluated into constants at compile time. Names are resolved as groups first.
+/// Groups defined by expressions cannot be assigned to variants with
+/// `#[groups(...)]`, and must not depend on themselves.
+///
+/// ```rust
+/// #[flagnum::flag(Days, groups(
+///     pub WEEKEND,
+///     pub WORKDAYS = !WEEKEND,
+///     pub LONG_WEEKEND = WEEKEND | Monday,
+///     pub ALL_BUT_MONDAY = FULL - Monday,
+/// ))]
+/// enum Day {
+///     Monday,
+///     Tuesday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+///
+/// assert_eq!(Days::WORKDAYS, Days::from([Day::Monday, Day::Tuesday]));
+/// assert_eq!(Days::LONG_WEEKEND, Days::from([Day::Monday, Day::Saturday, Day::Sunday]));
+/// assert_eq!(Days::ALL_BUT_MONDAY, Days::from([Day::Tuesday, Day::Saturday, Day::Sunday]));
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Days, groups(A = B | Monday, B = !A))]
+/// enum Day { Monday, Tuesday }
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Days, groups(A = Monday | Wednesday))]
+/// enum Day { Monday, Tuesday }
+/// ```
+///
+/// Groups marked as `exclusive` model choices where at most one of their items should
+/// be contained in a set. For these, the set type gets the inherent `const`
+/// functions `set_exclusive`, which removes the other items of the item's exclusive
+/// groups before inserting it, `selected_in`, returning the single item of a group
+/// contained in the set, and `validate_exclusive`, reporting violations as an
+/// [`ExclusiveGroupError`].
+///
+/// ```rust
+/// # use flagnum::Flags;
+/// #[flagnum::flag(Settings, groups(pub exclusive LEVEL))]
+/// enum Setting {
+///     Color,
+///     #[groups(LEVEL)]
+///     Quiet,
+///     #[groups(LEVEL)]
+///     Verbose,
+/// }
+///
+/// let mut options = Settings::from([Setting::Color, Setting::Quiet]);
+/// options.set_exclusive(Setting::Verbose);
+/// assert_eq!(options, Settings::from([Setting::Color, Setting::Verbose]));
+/// assert_eq!(options.selected_in(Settings::LEVEL), Some(Setting::Verbose));
+/// assert!(options.validate_exclusive().is_ok());
+///
+/// let error = Settings::FULL.validate_exclusive().unwrap_err();
+/// assert_eq!(error.group(), "LEVEL");
+/// assert_eq!(error.selected(), Settings::LEVEL);
+/// ```
+///
+/// ## `group_methods`
+///
+/// Generates inherent `const` methods on the set type for each group, named after the
+/// lowercased group name: `contains_all_<group>`, `contains_any_<group>`,
+/// `<group>_part` returning the contained items of the group, and `clear_<group>`.
+/// The methods use the visibility and attributes given to the group.
+///
+/// ```rust
+/// #[flagnum::flag(Days, groups(pub WEEKEND), group_methods)]
+/// enum Day {
+///     Monday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+///
+/// let mut days = Days::from([Day::Monday, Day::Sunday]);
+/// assert!(days.contains_any_weekend());
+/// assert!(!days.contains_all_weekend());
+/// assert_eq!(days.weekend_part(), Days::from(Day::Sunday));
+///
+/// days.clear_weekend();
+/// assert_eq!(days, Days::from(Day::Monday));
+/// ```
+///
+/// Method names that conflict with each other, with other inherent methods or with
+/// `item_accessors` are reported as errors on the group or variant.
+///
+/// ```compile_fail
+/// #[flagnum::flag(Days, groups(WEEKEND), group_methods, item_accessors)]
+/// enum Day {
+///     Monday,
+///     WeekendPart, // Conflicts with `Days::weekend_part`.
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+/// ```
+///
+/// ## `partition(...)`, `disjoint(...)` and `covers(...)`
+///
+/// Declare constraints on groups that are verified at compile time, reporting the
+/// offending variants on failure. Each argument is a group expression as described
+/// above, and constraints can be declared multiple times.
+///
+/// * `disjoint(A, B, ...)` requires that no variant is in more than one of the groups.
+/// * `covers(A, B, ...)` requires that every variant is in at least one of the groups.
+/// * `partition(A, B, ...)` requires both.
+///
+/// ```rust
+/// #[flagnum::flag(
+///     Days,
+///     groups(WEEKEND, WORKDAYS = !WEEKEND, EDGES = Monday | Sunday),
+///     partition(WEEKEND, WORKDAYS),
+///     disjoint(WEEKEND - Sunday, EDGES),
+/// )]
+/// enum Day {
+///     Monday,
+///     Tuesday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Days, groups(WEEKEND, WORKDAYS = Monday), partition(WEEKEND, WORKDAYS))]
+/// enum Day {
+///     Monday,
+///     Tuesday, // Not in any group.
+///     #[groups(WEEKEND)]
+///     Saturday,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// // Variant `Sunday` is in both `WEEKEND` and `EDGES`
+/// #[flagnum::flag(Days, groups(WEEKEND, EDGES = Monday | Sunday), disjoint(WEEKEND, EDGES))]
+/// enum Day {
+///     Monday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// // Variant `Tuesday` is in none of the groups of this `covers` constraint
+/// #[flagnum::flag(Days, groups(WEEKEND, EDGES = Monday | Sunday), covers(WEEKEND, EDGES))]
+/// enum Day {
+///     Monday,
+///     Tuesday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+/// ```
+///
+/// ## `group_enum(GroupItem, GroupSet)`
+///
+/// Generates an enum with a variant for each group listed in [`Flags::GROUPS`],
+/// together with its own flagnum set type. The variants are named in
+/// `UpperCamelCase`, while their item names match the group names. The generated
+/// API relating them consists of the inherent `const` functions:
+///
+/// * `GroupItem::items`, returning the set constant of the group.
+/// * `Item::group_set`, returning the set of groups containing the item.
+/// * `Set::complete_groups`, returning the non-empty groups fully contained in the set.
+/// * `Set::touched_groups`, returning the groups overlapping the set.
+///
+/// ```rust
+/// # use flagnum::{Flag, Flags};
+/// #[flagnum::flag(
+///     Days,
+///     groups(pub WEEKEND, pub WORKDAYS = !WEEKEND),
+///     group_enum(DayGroup, DayGroups),
+/// )]
+/// enum Day {
+///     Monday,
+///     #[groups(WEEKEND)]
+///     Saturday,
+///     #[groups(WEEKEND)]
+///     Sunday,
+/// }
+///
+/// assert_eq!(DayGroup::Weekend.items(), Days::WEEKEND);
+/// assert_eq!(DayGroup::Weekend.name(), "WEEKEND");
+/// assert_eq!(Day::Monday.group_set(), DayGroups::from(DayGroup::Workdays));
+///
+/// let days = Days::from([Day::Saturday, Day::Sunday]);
+/// assert_eq!(days.complete_groups(), DayGroups::from(DayGroup::Weekend));
+/// assert_eq!(days.with(Day::Monday).touched_groups(), DayGroups::FULL);
+/// ```
+///
+/// Groups whose names convert to the same variant name are reported as errors:
+///
+/// ```compile_fail
+/// #[flagnum::flag(
+///     Days,
+///     groups(pub WEEKEND, pub weekend = WEEKEND),
+///     group_enum(DayGroup, DayGroups),
+/// )]
+/// enum Day {
+///     #[groups(WEEKEND)]
+///     Monday,
+///     Sunday,
+/// }
+/// ```
+///
+/// ## `repr = <type>`
+///
+/// Fixes the representation of the set and the item discriminants to one of `u8`,
+/// `u16`, `u32`, `u64` or `u128`. Without this argument the smallest representation
+/// that fits all variants is chosen automatically.
+///
+/// ```rust
+/// #[flagnum::flag(Colors, repr = u32)]
+/// enum Color { Red, Green, Blue }
+///
+/// assert_eq!(std::mem::size_of::<Colors>(), 4);
+/// assert_eq!(std::mem::size_of::<Color>(), 4);
+/// ```
+///
+/// Adding more variants than the representation can hold is a compile error:
+///
+/// ```rust,compile_fail
+/// #[flagnum::flag(Colors, repr = u8)]
+/// enum Color { C0, C1, C2, C3, C4, C5, C6, C7, C8 }
+/// ```
+///
+/// ## `keep_discriminants`
+///
+/// Keeps the discriminants of the enum as written instead of replacing them with
+/// the bit values of the variants. This allows explicit discriminant values, which
+/// are otherwise rejected. The bit positions are then mapped from the variants
+/// separately, and the `#[repr]` of the enum is left to the user.
+///
+/// ```rust
+/// # use flagnum::Flags;
+/// #[flagnum::flag(Codes, keep_discriminants)]
+/// #[repr(u16)]
+/// enum Code {
+///     NotFound = 404,
+///     Gone = 410,
+///     Teapot = 418,
+/// }
+///
+/// assert_eq!(Code::Gone as u16, 410);
+/// assert_eq!(std::mem::size_of::<Codes>(), 1);
+/// assert!(Codes::from([Code::NotFound, Code::Gone, Code::Teapot]).is_full());
+/// ```
+///
+/// ## `retain_unknown_bits`
+///
+/// Allows the set to carry bits that don't belong to any item, as constructed by
+/// [`Flags::from_bits_retain`]. This keeps bits written by a newer version with more
+/// variants intact through a read-modify-write cycle.
+///
+/// Unknown bits are not considered items: [`Flags::len`], [`Flags::is_empty`],
+/// [`Flags::is_full`] and iteration ignore them, and [`Flags::missing`] and
+/// [`Flags::invert`] only flip the known bits. All other operations work on the
+/// raw bits. The [`Debug`](core::fmt::Debug) output includes the unknown bits as a
+/// trailing raw value. With serde, such sets are represented as a tuple of the
+/// sequence of known items and the raw unknown bits, like `[["Red"], 8]` in JSON. The
+/// fixed layout also round-trips through formats that don't describe their own
+/// types, like bincode or postcard.
+///
+/// ```rust
+/// # use flagnum::Flags;
+/// #[flagnum::flag(Colors, retain_unknown_bits)]
+/// enum Color { Red, Green, Blue }
+///
+/// let mut colors = Colors::from_bits_retain(0b1001);
+/// assert_eq!(colors, Colors::from(Color::Red).with(Colors::from_bits_retain(0b1000)));
+/// assert_eq!(colors.len(), 1);
+///
+/// colors.insert(Color::Green);
+/// colors.invert();
+/// assert_eq!(colors.bits(), 0b1100);
+/// assert_eq!(format!("{colors:?}"), "{Blue, UnknownBits { bits: 8 }}");
+/// ```
+///
+/// ## `item_const_fns`
+///
+/// Generates inherent `const` versions of [`Flag::index`], [`Flag::from_index`],
+/// [`Flag::bit`] and [`Flag::from_bit`] on the item type, with the visibility of the
+/// set type, so they can be used in `const` contexts.
+///
+/// ```rust
+/// #[flagnum::flag(Colors, item_const_fns)]
+/// enum Color { Red, Green, Blue }
+///
+/// const COUNTS: [u32; 3] = {
+///     let mut counts = [0; 3];
+///     counts[Color::Blue.index()] += 1;
+///     counts
+/// };
+/// assert_eq!(COUNTS, [0, 0, 1]);
+/// ```
+///
+/// The methods conflict with inherent methods of the same names defined on the enum,
+/// which is why they have to be requested explicitly:
+///
+/// ```compile_fail
+/// #[flagnum::flag(Colors, item_const_fns)]
+/// enum Color { Red, Green, Blue }
+///
+/// impl Color {
+///     fn index(self) -> u8 { self as u8 }
+/// }
+/// ```
+///
+/// ## `item_accessors` and `item_accessors = "..."`
+///
+/// Generates a single item set constant for each variant, named in
+/// `SCREAMING_SNAKE_CASE`, and inherent `const` accessor methods in the style of a
+/// struct of `bool` fields: a getter, a `set_` prefixed setter and a `toggle_`
+/// prefixed method. The method names are derived from the variant names with the
+/// given case convention, which is either `"snake_case"` (the default) or
+/// `"lowercase"`.
+///
+/// ```rust
+/// #[flagnum::flag(Days, item_accessors)]
+/// enum Day { Monday, LateFriday }
+///
+/// let mut days = Days::MONDAY;
+/// assert!(days.monday());
+/// assert!(!days.late_friday());
+///
+/// days.set_late_friday(true);
+/// days.toggle_monday();
+/// assert_eq!(days, Days::LATE_FRIDAY);
+/// ```
+///
+/// Constants and methods conflicting with other constants or inherent methods of the
+/// set type, or shadowing its trait methods like [`Flags::insert`], are reported at
+/// the variant:
+///
+/// ```compile_fail
+/// #[flagnum::flag(Measures, item_accessors)]
+/// enum Measure { Width, Len }
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Perms, item_accessors)]
+/// enum Perm { Read, Insert, Retain }
+/// ```
+///
+/// ## `rename_all = "..."`
+///
+/// Chooses the case convention of the item names used by [`Flag::name`],
+/// [`Flag::NAMES`], [`Flag::from_name`] and the generated
+/// [`Display`](core::fmt::Display) and [`FromStr`](core::str::FromStr) implementations.
+/// Supported values are `"snake_case"`, `"kebab-case"`, `"SCREAMING_SNAKE_CASE"` and
+/// `"verbatim"`, which uses the variant names as they are. Without this argument the
+/// variant names are used verbatim as well. The serde representation of items uses the
+/// same names.
+///
+/// ```rust
+/// # use flagnum::Flag;
+/// #[flagnum::flag(Formats, rename_all = "kebab-case")]
+/// enum Format { PlainText, HTMLPage, Json }
+///
+/// assert_eq!(Format::NAMES, &["plain-text", "html-page", "json"]);
+/// assert_eq!("html-page".parse::<Format>(), Ok(Format::HTMLPage));
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Colors, rename_all = "camelCase")]
+/// enum Color { Red, Green, Blue }
+/// ```
+///
+/// ## `separator = "..."`
+///
+/// Chooses the separator used by the textual representation of sets, which defaults to
+/// `"|"`. Set types implement [`Display`](core::fmt::Display) by joining the item names
+/// with the separator, and [`FromStr`](core::str::FromStr) by accepting item and group
+/// names separated by it, ignoring surrounding whitespace. Parse failures are reported
+/// as [`ParseSetError`]. Unknown bits retained with `retain_unknown_bits` are not part
+/// of the textual representation.
+///
+/// The separator must not occur in any item or group name. When a group has the same
+/// name as an item, parsing resolves the name to the item.
+///
+/// ```rust
+/// #[flagnum::flag(Colors, groups(WARM), separator = ",")]
+/// enum Color {
+///     #[groups(WARM)]
+///     Red,
+///     Green,
+///     #[groups(WARM)]
+///     Yellow,
+/// }
+///
+/// let colors: Colors = "Green, WARM".parse().unwrap();
+/// assert_eq!(colors.to_string(), "Red,Green,Yellow");
+/// assert_eq!(colors.to_string().parse(), Ok(colors));
+///
+/// let error = "Red,Blue".parse::<Colors>().unwrap_err();
+/// assert_eq!((error.token("Red,Blue"), error.offset()), (Some("Blue"), 4));
+/// ```
+///
+/// ## `skip_item_derives(...)` and `skip_set_derives(...)`
+///
+/// Skips the listed traits that would otherwise be automatically implemented for the
+/// item or set type, so they can be derived or implemented by hand instead. The
+/// standard traits `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and
+/// `Hash` can be skipped on both types, as well as `Display`, `FromStr`, `Serialize` and
+/// `Deserialize`. Set types additionally allow skipping `Binary`, `Octal`, `LowerHex`
+/// and `UpperHex`.
+///
+/// The traits required by [`Flag`] and [`Flags`] still have to be implemented. A
+/// missing implementation is reported once at the skipped trait name, without
+/// follow-up errors from the generated code. Remaining traits that can't be derived
+/// without a skipped one, like `Ord` without `PartialEq`, are implemented by the
+/// macro instead, ordering items the same way the derive would.
+///
+/// ```rust
+/// #[flagnum::flag(Colors, skip_item_derives(Debug))]
+/// enum Color { Red, Green, Blue }
+///
+/// impl core::fmt::Debug for Color {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         f.write_str("a color")
+///     }
+/// }
+///
+/// assert_eq!(format!("{:?}", Color::Green), "a color");
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Colors, skip_item_derives(Ord))]
+/// enum Color { Red, Green, Blue }
+/// ```
+///
+/// ```compile_fail
+/// #[flagnum::flag(Colors, skip_set_derives(PartialEq))]
+/// enum Color { Red, Green, Blue }
+/// ```
+///
+/// ## `crate = <path>`
+///
+/// Sets the path to the flagnum crate used by the generated code, which defaults to
+/// `::flagnum`. This is required when the dependency is renamed or flagnum is
+/// re-exported by another crate. All other paths in the generated code are absolute.
+///
+/// ```rust
+/// mod facade {
+///     pub mod inner {
+///         pub use flagnum::*;
+///     }
+/// }
+///
+/// #[facade::inner::flag(Colors, crate = facade::inner)]
+/// enum Color { Red, Green, Blue }
+///
+/// # use flagnum::Flags;
+/// assert_eq!(Colors::FULL.len(), 3);
+/// ```
+///
+/// # Variant Attributes
+///
+/// ## `#[groups(...)]`
+///
+/// Adds the variant to the listed groups declared with `groups(...)`.
+///
+/// ## `#[flagnum(bit = N)]`
+///
+/// Pins the variant to a specific bit position. Variants without an explicit
+/// position use the position following the previous variant, so positions can be
+/// skipped or reserved without affecting the bits of other variants.
+///
+/// ```rust
+/// # use flagnum::Flags;
+/// #[flagnum::flag(Colors)]
+/// enum Color {
+///     Red,
+///     #[flagnum(bit = 4)]
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_eq!(Colors::FULL.len(), 3);
+/// assert_eq!(Colors::ITEMS, &[Color::Red, Color::Green, Color::Blue]);
+/// assert_eq!(Color::Blue as u8, 1 << 5);
+/// ```
+///
+/// Assigning the same position to multiple variants is a compile error:
+///
+/// ```rust,compile_fail
+/// #[flagnum::flag(Colors)]
+/// enum Color {
+///     Red,
+///     #[flagnum(bit = 0)]
+///     Green,
+/// }
+/// ```
+///
+/// So is a position that doesn't fit into the explicit `repr`, or one above the
+/// supported maximum of 65535:
+///
+/// ```rust,compile_fail
+/// #[flagnum::flag(Colors, repr = u8)]
+/// enum Color {
+///     Red,
+///     #[flagnum(bit = 8)]
+///     Green,
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[flagnum::flag(Colors)]
+/// enum Color {
+///     Red,
+///     #[flagnum(bit = 65536)]
+///     Green,
+/// }
+/// ```
+///
+/// ## `#[flagnum(name = "...")]`
+///
+/// Uses the given name for the item instead of the one derived from the variant
+/// name and `rename_all`. Explicit names must be unique and non-empty.
+///
+/// ```rust
+/// # use flagnum::Flag;
+/// #[flagnum::flag(Colors, rename_all = "snake_case")]
+/// enum Color {
+///     Red,
+///     #[flagnum(name = "GREEN")]
+///     Green,
+/// }
+///
+/// assert_eq!(Color::NAMES, &["red", "GREEN"]);
+/// ```
 pub use flagnum_proc_macro::flag;
 
 extern crate self as flagnum;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod feature_alloc;
 
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 pub mod feature_serde;
 
+#[doc(hidden)]
+pub mod words;
+
+#[doc(hidden)]
+pub mod text;
+
 #[cfg(any(doctest, doc, test))]
 pub mod example;
 
@@ -54,15 +730,196 @@ pub mod example;
 /// * An `Into` bound for converting the item type into a corresponding set.
 /// * A `'static` lifetime bound.
 pub trait Flag: Sized
-    + std::fmt::Debug
+    + core::fmt::Debug
     + Clone + Copy
-    + PartialEq + Eq + PartialOrd + Ord + std::hash::Hash
+    + PartialEq + Eq + PartialOrd + Ord + core::hash::Hash
     + Send + Sync
     + Into<Self::Set>
     + 'static
 {
     /// The set type associated with this kind of item.
     type Set: Flags;
+
+    /// The number of items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Color::COUNT, 3);
+    /// ```
+    const COUNT: usize;
+
+    /// The position of the item in [`Flags::ITEMS`].
+    ///
+    /// This allows using items to index arrays of length [`Flag::COUNT`].
+    ///
+    /// Also available as an inherent `const` variant on item types generated with
+    /// `item_const_fns` ([Example](crate::example::Weekday::index)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let mut counts = [0; Color::COUNT];
+    /// counts[Color::Blue.index()] += 1;
+    /// assert_eq!(Color::Red.index(), 0);
+    /// assert_eq!(counts, [0, 0, 1]);
+    /// ```
+    #[must_use]
+    fn index(self) -> usize;
+
+    /// The item at the given position in [`Flags::ITEMS`].
+    ///
+    /// Also available as an inherent `const` variant on item types generated with
+    /// `item_const_fns` ([Example](crate::example::Weekday::from_index)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Color::from_index(1), Some(Color::Green));
+    /// assert_eq!(Color::from_index(3), None);
+    /// ```
+    #[must_use]
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// The bit position of the item in the set representation.
+    ///
+    /// Also available as an inherent `const` variant on item types generated with
+    /// `item_const_fns` ([Example](crate::example::Weekday::bit)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color {
+    ///     Red,
+    ///     #[flagnum(bit = 4)]
+    ///     Green,
+    ///     Blue,
+    /// }
+    ///
+    /// assert_eq!(Color::Red.bit(), 0);
+    /// assert_eq!(Color::Green.bit(), 4);
+    /// assert_eq!(Color::Blue.bit(), 5);
+    /// ```
+    #[must_use]
+    fn bit(self) -> usize;
+
+    /// The item at the given bit position in the set representation.
+    ///
+    /// Also available as an inherent `const` variant on item types generated with
+    /// `item_const_fns` ([Example](crate::example::Weekday::from_bit)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color {
+    ///     Red,
+    ///     #[flagnum(bit = 4)]
+    ///     Green,
+    ///     Blue,
+    /// }
+    ///
+    /// assert_eq!(Color::from_bit(4), Some(Color::Green));
+    /// assert_eq!(Color::from_bit(1), None);
+    /// ```
+    #[must_use]
+    fn from_bit(position: usize) -> Option<Self>;
+
+    /// The names of all items, in the same order as [`Flags::ITEMS`].
+    ///
+    /// Names follow the `rename_all` convention of the [`flag`] attribute, and default
+    /// to the variant names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Color::NAMES, &["Red", "Green", "Blue"]);
+    /// ```
+    const NAMES: &'static [&'static str];
+
+    /// The name of the item.
+    ///
+    /// This is also used by the generated [`Display`](core::fmt::Display) implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors, rename_all = "snake_case")]
+    /// enum Color { Red, LightGreen, Blue }
+    ///
+    /// assert_eq!(Color::LightGreen.name(), "light_green");
+    /// assert_eq!(Color::LightGreen.to_string(), "light_green");
+    /// ```
+    #[must_use]
+    fn name(self) -> &'static str;
+
+    /// The item with the given name.
+    ///
+    /// Names are matched exactly. This is also used by the generated
+    /// [`FromStr`](core::str::FromStr) implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Color::from_name("Green"), Some(Color::Green));
+    /// assert_eq!(Color::from_name("green"), None);
+    /// assert_eq!("Blue".parse::<Color>(), Ok(Color::Blue));
+    /// assert!("Purple".parse::<Color>().is_err());
+    /// ```
+    #[must_use]
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// An iterator over the exposed groups containing the item.
+    ///
+    /// The groups are taken from [`Flags::GROUPS`] and yielded with their names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flag;
+    /// #[flagnum::flag(Colors, groups(pub WARM, pub PRIMARY))]
+    /// enum Color {
+    ///     #[groups(WARM, PRIMARY)]
+    ///     Red,
+    ///     #[groups(WARM)]
+    ///     Yellow,
+    ///     Green,
+    /// }
+    ///
+    /// let names: Vec<&str> = Color::Red.groups().map(|(name, _)| name).collect();
+    /// assert_eq!(names, ["WARM", "PRIMARY"]);
+    /// assert_eq!(Color::Green.groups().count(), 0);
+    /// ```
+    fn groups(self) -> ItemGroups<Self::Set> {
+        ItemGroups {
+            item: self.into(),
+            groups: <Self::Set as Flags>::GROUPS.iter(),
+        }
+    }
 }
 
 /// A trait implemented by all generated set types.
@@ -71,6 +928,9 @@ pub trait Flag: Sized
 /// The constructors do have generic counterparts, but they are still provided as
 /// part of the trait interface for symmetry.
 ///
+/// Methods accepting anything that can be turned into a set have their inherent
+/// `const` variants prefixed with `const_`, since those take a concrete set instead.
+///
 /// You can see the [`Weekdays`](crate::example::Weekdays) example for a list of
 /// generated inherent members.
 ///
@@ -81,9 +941,9 @@ pub trait Flag: Sized
 /// * A `From` bound for converting from the corresponding item type.
 /// * A `'static` lifetime bound.
 pub trait Flags: Sized
-    + std::fmt::Debug
+    + core::fmt::Debug
     + Clone + Copy
-    + PartialEq + Eq + PartialOrd + Ord + std::hash::Hash
+    + PartialEq + Eq + PartialOrd + Ord + core::hash::Hash
     + Send + Sync
     + From<Self::Item>
     + 'static
@@ -93,6 +953,12 @@ pub trait Flags: Sized
     /// This is the type of the enum the set type was generated for.
     type Item: Flag;
 
+    /// The raw bits representation of the set.
+    ///
+    /// This is an unsigned integer type, or an array of `u64` words for sets with
+    /// more than 128 items.
+    type Bits: Bits;
+
     /// A predefined empty set.
     ///
     /// # Example
@@ -140,6 +1006,31 @@ pub trait Flags: Sized
     /// ```
     const ITEMS: &'static [Self::Item];
 
+    /// A static slice of the exposed groups with their names, in declaration order.
+    ///
+    /// Only groups declared with a visibility are exposed, and `#[cfg(...)]` attributes
+    /// on a group apply to its entry as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors, groups(pub WARM, COLD))]
+    /// enum Color {
+    ///     #[groups(WARM)]
+    ///     Red,
+    ///     #[groups(COLD)]
+    ///     Blue,
+    /// }
+    ///
+    /// assert_eq!(Colors::GROUPS, &[("WARM", Colors::WARM)]);
+    /// ```
+    const GROUPS: &'static [(&'static str, Self)];
+
+    /// Whether the bit positions of the items ascend in [`Flags::ITEMS`] order.
+    #[doc(hidden)]
+    const __BITS_IN_ITEM_ORDER: bool;
+
     /// Construct a set from a single item.
     ///
     /// Also available as an inherent `const` variant on the generated set types
@@ -198,6 +1089,87 @@ pub trait Flags: Sized
     #[must_use]
     fn from_sets(items: &[Self]) -> Self;
 
+    /// Construct a set from raw bits, failing if any bits don't belong to an item.
+    ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::from_bits)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Colors::from_bits(0b101), Ok(Colors::from([Color::Red, Color::Blue])));
+    ///
+    /// let error = Colors::from_bits(0b1010).unwrap_err();
+    /// assert_eq!(error.bits(), 0b1000);
+    /// ```
+    fn from_bits(bits: Self::Bits) -> Result<Self, UnknownBits<Self::Bits>>;
+
+    /// Construct a set from raw bits, discarding any bits that don't belong to an item.
+    ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::from_bits_truncate)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Colors::from_bits_truncate(0b1010), Color::Green.into());
+    /// assert_eq!(Colors::from_bits_truncate(u8::MAX), Colors::FULL);
+    /// ```
+    #[must_use]
+    fn from_bits_truncate(bits: Self::Bits) -> Self;
+
+    /// Construct a set from raw bits, retaining bits that don't belong to an item.
+    ///
+    /// Unknown bits are only retained by set types declared with the
+    /// `retain_unknown_bits` argument (see [`flag`](crate::flag#retain_unknown_bits)).
+    /// For all other set types this is equivalent to [`Flags::from_bits_truncate`].
+    ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::from_bits_retain)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// #[flagnum::flag(RetainingColors, retain_unknown_bits)]
+    /// enum RetainingColor { Red, Green, Blue }
+    ///
+    /// assert_eq!(Colors::from_bits_retain(0b1010).bits(), 0b0010);
+    /// assert_eq!(RetainingColors::from_bits_retain(0b1010).bits(), 0b1010);
+    /// ```
+    #[must_use]
+    fn from_bits_retain(bits: Self::Bits) -> Self;
+
+    /// The raw bits of the set.
+    ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::bits)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// assert_eq!(Colors::from([Color::Red, Color::Blue]).bits(), 0b101);
+    /// assert_eq!(Colors::EMPTY.bits(), 0);
+    /// assert_eq!(Colors::FULL.bits(), 0b111);
+    /// ```
+    #[must_use]
+    fn bits(self) -> Self::Bits;
+
     /// The number of items in the set.
     ///
     /// Also available as an inherent `const` variant on the generated set types
@@ -258,7 +1230,155 @@ pub trait Flags: Sized
     #[must_use]
     fn is_full(self) -> bool;
 
-    /// Predicate to check if one set contains another.
+    /// Predicate to check if one set contains another.
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_contains)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let colors = Colors::from_item(Color::Green);
+    /// assert!(Colors::FULL.contains(colors));
+    /// assert!(! Colors::EMPTY.contains(colors));
+    /// assert!(colors.contains(Color::Green));
+    /// assert!(! colors.contains(Color::Red));
+    /// ```
+    #[must_use]
+    fn contains<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// Predicate to check if two sets have any overlap.
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_has_overlap)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    /// let gb = Colors::from_iter([Color::Green, Color::Blue]);
+    ///
+    /// assert!(rg.has_overlap(gb));
+    /// assert!(! rg.has_overlap(Color::Blue));
+    /// ```
+    #[must_use]
+    fn has_overlap<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// Predicate to check if all items of the set are contained in another.
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_is_subset_of)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    ///
+    /// assert!(rg.is_subset_of(Colors::FULL));
+    /// assert!(rg.is_subset_of(rg));
+    /// assert!(Colors::EMPTY.is_subset_of(rg));
+    /// assert!(! rg.is_subset_of(Color::Red));
+    /// ```
+    #[must_use]
+    fn is_subset_of<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// Predicate to check if all items of the set are contained in another, and the
+    /// other set contains additional items.
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_is_strict_subset_of)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    ///
+    /// assert!(rg.is_strict_subset_of(Colors::FULL));
+    /// assert!(! rg.is_strict_subset_of(rg));
+    /// assert!(! Colors::FULL.is_strict_subset_of(rg));
+    /// ```
+    #[must_use]
+    fn is_strict_subset_of<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// Predicate to check if the set contains all items of another.
+    ///
+    /// This is equivalent to [`Flags::contains`].
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_is_superset_of)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    ///
+    /// assert!(rg.is_superset_of(Color::Red));
+    /// assert!(rg.is_superset_of(Colors::EMPTY));
+    /// assert!(! rg.is_superset_of(Colors::FULL));
+    /// ```
+    #[must_use]
+    fn is_superset_of<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// Predicate to check if two sets have no items in common.
+    ///
+    /// This is the inverse of [`Flags::has_overlap`].
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_is_disjoint)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors)]
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    ///
+    /// assert!(rg.is_disjoint(Color::Blue));
+    /// assert!(! rg.is_disjoint(Color::Green));
+    /// ```
+    #[must_use]
+    fn is_disjoint<T>(self, other: T) -> bool
+    where
+        T: Into<Self>;
+
+    /// A set containing the items found in exactly one of both sets.
+    ///
+    /// This is the functional version of [`Flags::toggle`].
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_symmetric_difference)).
     ///
     /// # Example
     ///
@@ -267,18 +1387,22 @@ pub trait Flags: Sized
     /// #[flagnum::flag(Colors)]
     /// enum Color { Red, Green, Blue }
     ///
-    /// let colors = Colors::from_item(Color::Green);
-    /// assert!(Colors::FULL.contains(colors));
-    /// assert!(! Colors::EMPTY.contains(colors));
-    /// assert!(colors.contains(Color::Green));
-    /// assert!(! colors.contains(Color::Red));
+    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
+    /// let gb = Colors::from_iter([Color::Green, Color::Blue]);
+    ///
+    /// assert_eq!(rg.symmetric_difference(gb), Colors::from([Color::Red, Color::Blue]));
     /// ```
     #[must_use]
-    fn contains<T>(self, other: T) -> bool
+    fn symmetric_difference<T>(self, other: T) -> Self
     where
         T: Into<Self>;
 
-    /// Predicate to check if two sets have any overlap.
+    /// Add the items of another set not currently in the set, and remove those that are.
+    ///
+    /// This is the self-modifying version of [`Flags::symmetric_difference`].
+    ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_toggle)).
     ///
     /// # Example
     ///
@@ -287,19 +1411,21 @@ pub trait Flags: Sized
     /// #[flagnum::flag(Colors)]
     /// enum Color { Red, Green, Blue }
     ///
-    /// let rg = Colors::from_iter([Color::Red, Color::Green]);
-    /// let gb = Colors::from_iter([Color::Green, Color::Blue]);
-    ///
-    /// assert!(rg.has_overlap(gb));
-    /// assert!(! rg.has_overlap(Color::Blue));
+    /// let mut colors = Colors::from_iter([Color::Red, Color::Green]);
+    /// colors.toggle(Color::Red);
+    /// assert_eq!(colors, Color::Green.into());
+    /// colors.toggle([Color::Green, Color::Blue]);
+    /// assert_eq!(colors, Color::Blue.into());
     /// ```
-    #[must_use]
-    fn has_overlap<T>(self, other: T) -> bool
+    fn toggle<T>(&mut self, other: T)
     where
         T: Into<Self>;
 
     /// A set containing items common to both sets.
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_overlap)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -321,6 +1447,9 @@ pub trait Flags: Sized
     ///
     /// This is the functional version of [`Flags::insert`].
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_with)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -343,6 +1472,9 @@ pub trait Flags: Sized
     ///
     /// This is the functional version of [`Flags::remove`].
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_without)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -365,6 +1497,9 @@ pub trait Flags: Sized
     ///
     /// This is the functional version of [`Flags::invert`].
     ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::missing)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -385,6 +1520,9 @@ pub trait Flags: Sized
     ///
     /// This is the self-modifying version of [`Flags::missing`].
     ///
+    /// Also available as an inherent `const` variant on the generated set types
+    /// ([Example](crate::example::Weekdays::invert)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -402,6 +1540,9 @@ pub trait Flags: Sized
     ///
     /// This is the self-modifying version of [`Flags::with`].
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_insert)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -421,6 +1562,9 @@ pub trait Flags: Sized
     ///
     /// This is the self-modifying version of [`Flags::without`].
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_remove)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -442,6 +1586,9 @@ pub trait Flags: Sized
     /// [`Flags::retained`].
     /// It is also a self-modifying version of [`Flags::overlap`].
     ///
+    /// Also available as an inherent `const` variant taking a concrete set on the
+    /// generated set types ([Example](crate::example::Weekdays::const_keep)).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -497,6 +1644,383 @@ pub trait Flags: Sized
     fn retained<F>(self, is_retained: F) -> Self
     where
         F: FnMut(Self::Item) -> bool;
+
+    /// An adapter formatting the set with fully contained exposed groups collapsed into
+    /// their names.
+    ///
+    /// See [`DisplayGrouped`] for details on the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use flagnum::Flags;
+    /// #[flagnum::flag(Colors, groups(pub WARM))]
+    /// enum Color {
+    ///     #[groups(WARM)]
+    ///     Red,
+    ///     #[groups(WARM)]
+    ///     Yellow,
+    ///     Blue,
+    /// }
+    ///
+    /// let colors = Colors::from([Color::Red, Color::Yellow, Color::Blue]);
+    /// assert_eq!(format!("{colors:?}"), "{Red, Yellow, Blue}");
+    /// assert_eq!(format!("{:?}", colors.display_grouped()), "{WARM, Blue}");
+    /// ```
+    #[must_use]
+    fn display_grouped(self) -> DisplayGrouped<Self> {
+        DisplayGrouped { set: self }
+    }
+}
+
+/// A trait implemented by all raw bit representations of sets.
+///
+/// See [`Flags::Bits`].
+pub trait Bits: Sized
+    + core::fmt::Debug
+    + Clone + Copy
+    + PartialEq + Eq + PartialOrd + Ord + core::hash::Hash
+    + Send + Sync
+    + 'static
+{
+    #[doc(hidden)]
+    const BITS: usize;
+
+    #[doc(hidden)]
+    fn count(self) -> usize;
+
+    #[doc(hidden)]
+    fn lowest_position(self) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn highest_position(self) -> Option<usize>;
+
+    #[doc(hidden)]
+    #[must_use]
+    fn clear_position(self, position: usize) -> Self;
+
+    #[doc(hidden)]
+    fn has_position(self, position: usize) -> bool;
+}
+
+macro_rules! impl_int_bits {
+    ($( $int:ty ),*) => {
+        $(
+            impl Bits for $int {
+                const BITS: usize = <$int>::BITS as usize;
+
+                fn count(self) -> usize {
+                    self.count_ones() as usize
+                }
+
+                fn lowest_position(self) -> Option<usize> {
+                    (self != 0).then(|| self.trailing_zeros() as usize)
+                }
+
+                fn highest_position(self) -> Option<usize> {
+                    (self != 0).then(|| (<$int>::BITS - 1 - self.leading_zeros()) as usize)
+                }
+
+                fn clear_position(self, position: usize) -> Self {
+                    self & !(1 << position)
+                }
+
+                fn has_position(self, position: usize) -> bool {
+                    self & (1 << position) != 0
+                }
+            }
+        )*
+    }
+}
+
+impl_int_bits!(u8, u16, u32, u64, u128);
+
+impl<const N: usize> Bits for [words::Word; N] {
+
+    const BITS: usize = N * words::WORD_BITS;
+
+    fn count(self) -> usize {
+        words::count_ones(self)
+    }
+
+    fn lowest_position(self) -> Option<usize> {
+        words::lowest_position(self)
+    }
+
+    fn highest_position(self) -> Option<usize> {
+        words::highest_position(self)
+    }
+
+    fn clear_position(self, position: usize) -> Self {
+        words::difference(self, words::bit(position))
+    }
+
+    fn has_position(self, position: usize) -> bool {
+        !words::is_empty(words::intersection(self, words::bit(position)))
+    }
+}
+
+/// The error returned when constructing a set from raw bits that contain bits not
+/// belonging to any item.
+///
+/// See [`Flags::from_bits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnknownBits<B> {
+    bits: B,
+}
+
+impl<B> UnknownBits<B> {
+
+    #[doc(hidden)]
+    pub const fn new(bits: B) -> Self {
+        Self { bits }
+    }
+
+    /// The bits that don't belong to any item.
+    pub fn bits(&self) -> B
+    where
+        B: Copy,
+    {
+        self.bits
+    }
+}
+
+impl<B> core::fmt::Display for UnknownBits<B>
+where
+    B: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown set bits {:?}", self.bits)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B> std::error::Error for UnknownBits<B>
+where
+    B: core::fmt::Debug,
+{
+}
+
+/// The error returned when a set contains more than one item of an exclusive group.
+///
+/// See the `exclusive` marker of the [`flag`] attribute's `groups(...)` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExclusiveGroupError<T> {
+    group: &'static str,
+    selected: T,
+}
+
+impl<T> ExclusiveGroupError<T> {
+
+    #[doc(hidden)]
+    pub const fn new(group: &'static str, selected: T) -> Self {
+        Self { group, selected }
+    }
+
+    /// The name of the violated group.
+    pub fn group(&self) -> &'static str {
+        self.group
+    }
+
+    /// The items of the group contained in the set.
+    pub fn selected(&self) -> T
+    where
+        T: Copy,
+    {
+        self.selected
+    }
+}
+
+impl<T> core::fmt::Display for ExclusiveGroupError<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "multiple items {:?} selected in exclusive group {}", self.selected, self.group)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for ExclusiveGroupError<T>
+where
+    T: core::fmt::Debug,
+{
+}
+
+/// The error returned when parsing an item from a name that doesn't belong to any item.
+///
+/// See [`Flag::from_name`]. The unknown name is the whole parsed text, so the error
+/// doesn't repeat it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseItemError {
+    _private: (),
+}
+
+impl ParseItemError {
+
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl core::fmt::Display for ParseItemError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown item name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseItemError {}
+
+/// The error returned when parsing a set from text containing a token that is neither
+/// an item nor a group name.
+///
+/// The error locates the token by its byte range in the parsed text, so it doesn't
+/// require an allocation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseSetError {
+    offset: usize,
+    len: usize,
+}
+
+impl ParseSetError {
+
+    #[doc(hidden)]
+    pub fn new(token: &str, offset: usize) -> Self {
+        Self { offset, len: token.len() }
+    }
+
+    /// The byte offset of the token in the parsed text.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte range of the token in the parsed text.
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.offset..self.offset + self.len
+    }
+
+    /// The token that didn't match any item or group, given the parsed `text`.
+    ///
+    /// Returns `None` if `text` isn't the text that was parsed.
+    pub fn token<'a>(&self, text: &'a str) -> Option<&'a str> {
+        text.get(self.range())
+    }
+}
+
+impl core::fmt::Display for ParseSetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown set element at byte offset {}", self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSetError {}
+
+/// An iterator over the exposed groups containing an item.
+///
+/// Created by [`Flag::groups`].
+#[derive(Debug, Clone)]
+pub struct ItemGroups<T>
+where
+    T: Flags,
+{
+    item: T,
+    groups: core::slice::Iter<'static, (&'static str, T)>,
+}
+
+impl<T> Iterator for ItemGroups<T>
+where
+    T: Flags,
+{
+    type Item = (&'static str, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.item;
+        self.groups.by_ref().copied().find(|(_, group)| group.contains(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.groups.size_hint().1)
+    }
+}
+
+impl<T> core::iter::FusedIterator for ItemGroups<T>
+where
+    T: Flags,
+{
+}
+
+/// Formats a set with fully contained groups collapsed into their names.
+///
+/// The exposed groups in [`Flags::GROUPS`] are considered in declaration order. A
+/// non-empty group is listed by name if all its items are in the set, it isn't a
+/// strict subset of another group fully contained in the set, and it isn't already
+/// covered by previously listed groups. The remaining items are listed afterwards.
+///
+/// Only groups declared with a visibility, such as `groups(pub WEEKEND)`, are part of
+/// [`Flags::GROUPS`]. Private groups are never collapsed, and their items are listed
+/// individually.
+///
+/// Both the [`Debug`](core::fmt::Debug) and [`Display`](core::fmt::Display)
+/// implementations produce set notation, and `{:#?}` gives the pretty-printed variant.
+///
+/// Created by [`Flags::display_grouped`].
+#[derive(Clone, Copy)]
+pub struct DisplayGrouped<T> {
+    set: T,
+}
+
+impl<T> DisplayGrouped<T>
+where
+    T: Flags,
+{
+    fn fmt_set(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Name(&'static str);
+
+        impl core::fmt::Debug for Name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        let is_listable = |group: T| !group.is_empty() && self.set.contains(group);
+        let mut set = f.debug_set();
+        let mut covered = T::EMPTY;
+        for &(name, group) in T::GROUPS {
+            let is_subsumed = T::GROUPS
+                .iter()
+                .any(|&(_, other)| is_listable(other) && group.is_strict_subset_of(other));
+            if is_listable(group) && !is_subsumed && !covered.contains(group) {
+                set.entry(&Name(name));
+                covered.insert(group);
+            }
+        }
+        set.entries(Iter::new(self.set.without(covered)));
+        if let Err(unknown) = T::from_bits(self.set.bits()) {
+            set.entry(&unknown);
+        }
+        set.finish()
+    }
+}
+
+impl<T> core::fmt::Debug for DisplayGrouped<T>
+where
+    T: Flags,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_set(f)
+    }
+}
+
+impl<T> core::fmt::Display for DisplayGrouped<T>
+where
+    T: Flags,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_set(f)
+    }
 }
 
 /// An iterator over the items in a [flagnum] set.
@@ -520,13 +2044,35 @@ pub trait Flags: Sized
 /// let colors: Vec<Color> = iter.collect();
 /// assert_eq!(colors, vec![Color::Red, Color::Green, Color::Blue]);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+///
+/// Items are produced in [`Flags::ITEMS`] order, also when bit positions are pinned
+/// with `#[flagnum(bit = N)]`.
+///
+/// The iterator scans the set bits directly when the bit positions follow the item
+/// order, can be iterated from both ends and knows its exact length:
+///
+/// ```rust
+/// # use flagnum::{Flags, Iter};
+/// #[flagnum::flag(Colors)]
+/// enum Color { Red, Green, Blue }
+///
+/// let mut iter = Colors::FULL.into_iter();
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.next_back(), Some(Color::Blue));
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.rev().collect::<Vec<_>>(), vec![Color::Green, Color::Red]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Iter<T> {
     items: T,
-    offset: usize,
+    front: usize,
+    back: usize,
 }
 
-impl<T> Iter<T> {
+impl<T> Iter<T>
+where
+    T: Flags,
+{
 
     /// Construct an iterator over all items in the given set.
     ///
@@ -543,12 +2089,36 @@ impl<T> Iter<T> {
     /// ```
     pub fn new(set: T) -> Self {
         Self {
-            items: set,
-            offset: 0,
+            items: T::from_bits_truncate(set.bits()),
+            front: 0,
+            back: T::ITEMS.len(),
+        }
+    }
+
+    fn take_at(&mut self, position: usize) -> Option<T::Item> {
+        self.items = T::from_bits_truncate(self.items.bits().clear_position(position));
+        T::Item::from_bit(position)
+    }
+
+    fn take_item(&mut self, item: T::Item) -> Option<T::Item> {
+        if self.items.contains(item) {
+            self.items.remove(item);
+            Some(item)
+        } else {
+            None
         }
     }
 }
 
+impl<T> Default for Iter<T>
+where
+    T: Flags + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
 impl<T> Iterator for Iter<T>
 where
     T: Flags,
@@ -556,12 +2126,61 @@ where
     type Item = T::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(item) = T::ITEMS.get(self.offset).copied() {
-            self.offset += 1;
-            if self.items.contains(item) {
+        if T::__BITS_IN_ITEM_ORDER {
+            let position = self.items.bits().lowest_position()?;
+            return self.take_at(position);
+        }
+        while !self.items.is_empty() && self.front < self.back {
+            let item = T::ITEMS[self.front];
+            self.front += 1;
+            if let Some(item) = self.take_item(item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.items.bits().count();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<T>
+where
+    T: Flags,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if T::__BITS_IN_ITEM_ORDER {
+            let position = self.items.bits().highest_position()?;
+            return self.take_at(position);
+        }
+        while !self.items.is_empty() && self.front < self.back {
+            self.back -= 1;
+            if let Some(item) = self.take_item(T::ITEMS[self.back]) {
                 return Some(item);
             }
         }
         None
     }
 }
+
+impl<T> ExactSizeIterator for Iter<T>
+where
+    T: Flags,
+{
+}
+
+impl<T> core::iter::FusedIterator for Iter<T>
+where
+    T: Flags,
+{
+}
@@ -0,0 +1,108 @@
+//! Support functions for the generated [`Display`](core::fmt::Display) and
+//! [`FromStr`](core::str::FromStr) implementations of set types.
+
+use core::fmt::{Alignment, Write};
+
+use crate::{Bits, Flag, Flags, ParseSetError};
+
+/// Write the names of all items in `set`, joined by `separator`.
+pub fn fmt_set<S>(set: S, separator: &str, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+where
+    S: Flags,
+{
+    for (index, item) in crate::Iter::new(set).enumerate() {
+        if index > 0 {
+            f.write_str(separator)?;
+        }
+        f.write_str(item.name())?;
+    }
+    Ok(())
+}
+
+/// Parse a `separator` delimited list of names into a set.
+///
+/// Surrounding whitespace is ignored for the whole text and for each token. An empty
+/// text parses as the empty set.
+pub fn parse_set<S>(
+    text: &str,
+    separator: &str,
+    lookup: impl Fn(&str) -> Option<S>,
+) -> Result<S, ParseSetError>
+where
+    S: Flags,
+{
+    let mut set = S::EMPTY;
+    if text.trim().is_empty() {
+        return Ok(set);
+    }
+    let mut offset = 0;
+    for part in text.split(separator) {
+        let token = part.trim();
+        let token_offset = offset + (part.len() - part.trim_start().len());
+        match lookup(token) {
+            Some(found) => set.insert(found),
+            None => return Err(ParseSetError::new(token, token_offset)),
+        }
+        offset += part.len() + separator.len();
+    }
+    Ok(set)
+}
+
+/// Format `bits` as a number in a power of two radix with `digit_bits` bits per digit.
+///
+/// The alternate flag adds the `prefix` and zero-pads to the full width of the
+/// representation. Width, fill, alignment and the `0` flag are handled like
+/// [`Formatter::pad_integral`](core::fmt::Formatter::pad_integral) does, without
+/// requiring an allocation for the digits.
+pub fn fmt_radix<B>(
+    bits: B,
+    digit_bits: usize,
+    uppercase: bool,
+    prefix: &str,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result
+where
+    B: Bits,
+{
+    let digit_count = if f.alternate() {
+        B::BITS.div_ceil(digit_bits)
+    } else {
+        bits.highest_position().map_or(1, |position| position / digit_bits + 1)
+    };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let len = prefix.len() + digit_count;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let write_digits = |f: &mut core::fmt::Formatter<'_>| {
+        for digit in (0..digit_count).rev() {
+            let value = (0..digit_bits)
+                .map(|offset| digit * digit_bits + offset)
+                .filter(|&position| position < B::BITS && bits.has_position(position))
+                .fold(0, |value, position| value | 1 << (position % digit_bits));
+            let c = char::from_digit(value, 1 << digit_bits).unwrap();
+            f.write_char(if uppercase { c.to_ascii_uppercase() } else { c })?;
+        }
+        Ok(())
+    };
+    if f.sign_aware_zero_pad() {
+        f.write_str(prefix)?;
+        write_fill(f, '0', padding)?;
+        return write_digits(f);
+    }
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, before)?;
+    f.write_str(prefix)?;
+    write_digits(f)?;
+    write_fill(f, fill, after)
+}
+
+fn write_fill(f: &mut core::fmt::Formatter<'_>, fill: char, count: usize) -> core::fmt::Result {
+    for _ in 0..count {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
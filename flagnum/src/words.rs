@@ -0,0 +1,116 @@
+//! `const` bit operations on the word array representation used for sets with more
+//! than 128 items.
+//!
+//! Bit positions are stored in little-endian word order, so position `0` is the lowest
+//! bit of the first word.
+
+/// The word type used by the multi-word set representation.
+pub type Word = u64;
+
+/// The number of bits in a single [`Word`].
+pub const WORD_BITS: usize = Word::BITS as usize;
+
+pub const fn empty<const N: usize>() -> [Word; N] {
+    [0; N]
+}
+
+pub const fn bit<const N: usize>(position: usize) -> [Word; N] {
+    let mut words = [0; N];
+    words[position / WORD_BITS] = 1 << (position % WORD_BITS);
+    words
+}
+
+pub const fn union<const N: usize>(a: [Word; N], b: [Word; N]) -> [Word; N] {
+    let mut words = [0; N];
+    let mut index = 0;
+    while index < N {
+        words[index] = a[index] | b[index];
+        index += 1;
+    }
+    words
+}
+
+pub const fn intersection<const N: usize>(a: [Word; N], b: [Word; N]) -> [Word; N] {
+    let mut words = [0; N];
+    let mut index = 0;
+    while index < N {
+        words[index] = a[index] & b[index];
+        index += 1;
+    }
+    words
+}
+
+pub const fn difference<const N: usize>(a: [Word; N], b: [Word; N]) -> [Word; N] {
+    let mut words = [0; N];
+    let mut index = 0;
+    while index < N {
+        words[index] = a[index] & !b[index];
+        index += 1;
+    }
+    words
+}
+
+pub const fn symmetric_difference<const N: usize>(a: [Word; N], b: [Word; N]) -> [Word; N] {
+    let mut words = [0; N];
+    let mut index = 0;
+    while index < N {
+        words[index] = a[index] ^ b[index];
+        index += 1;
+    }
+    words
+}
+
+pub const fn is_empty<const N: usize>(words: [Word; N]) -> bool {
+    let mut index = 0;
+    while index < N {
+        if words[index] != 0 {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+pub const fn eq<const N: usize>(a: [Word; N], b: [Word; N]) -> bool {
+    let mut index = 0;
+    while index < N {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+pub const fn count_ones<const N: usize>(words: [Word; N]) -> usize {
+    let mut count = 0;
+    let mut index = 0;
+    while index < N {
+        count += words[index].count_ones() as usize;
+        index += 1;
+    }
+    count
+}
+
+pub const fn lowest_position<const N: usize>(words: [Word; N]) -> Option<usize> {
+    let mut index = 0;
+    while index < N {
+        if words[index] != 0 {
+            return Some(index * WORD_BITS + words[index].trailing_zeros() as usize);
+        }
+        index += 1;
+    }
+    None
+}
+
+pub const fn highest_position<const N: usize>(words: [Word; N]) -> Option<usize> {
+    let mut index = N;
+    while index > 0 {
+        index -= 1;
+        if words[index] != 0 {
+            let offset = WORD_BITS - 1 - words[index].leading_zeros() as usize;
+            return Some(index * WORD_BITS + offset);
+        }
+    }
+    None
+}
@@ -0,0 +1,89 @@
+use std::mem::size_of;
+
+use flagnum::Flags;
+
+#[flagnum::flag(Set, groups(GROUP))]
+enum Item {
+    #[flagnum(bit = 2)]
+    A,
+    B,
+    #[groups(GROUP)]
+    #[flagnum(bit = 7)]
+    C,
+    #[flagnum(bit = 0)]
+    D,
+}
+
+#[test]
+fn discriminants() {
+    assert_eq!(Item::A as u8, 1 << 2);
+    assert_eq!(Item::B as u8, 1 << 3);
+    assert_eq!(Item::C as u8, 1 << 7);
+    assert_eq!(Item::D as u8, 1 << 0);
+    assert_eq!(size_of::<Set>(), 1);
+}
+
+#[test]
+fn gaps() {
+    assert_eq!(Set::ITEMS, &[Item::A, Item::B, Item::C, Item::D]);
+    assert_eq!(Set::FULL.len(), 4);
+    assert!(Set::from([Item::A, Item::B, Item::C, Item::D]).is_full());
+    assert_eq!(Set::GROUP, Item::C.into());
+    assert_eq!(Set::from(Item::A).missing(), Set::from([Item::B, Item::C, Item::D]));
+    assert_eq!(
+        Set::FULL.into_iter().collect::<Vec<_>>(),
+        Vec::from([Item::A, Item::B, Item::C, Item::D]),
+    );
+    assert_eq!(Set::FULL.retained(|item| item != Item::C).len(), 3);
+}
+
+#[test]
+fn representation_growth() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 8)]
+        B,
+    }
+
+    assert_eq!(size_of::<Set>(), 2);
+    assert_eq!(Set::FULL.len(), 2);
+}
+
+#[test]
+fn limits() {
+    #[flagnum::flag(Bytes, repr = u8)]
+    enum Byte {
+        A,
+        #[flagnum(bit = 7)]
+        B,
+    }
+
+    #[flagnum::flag(Wide)]
+    enum WideItem {
+        A,
+        #[flagnum(bit = 65535)]
+        B,
+    }
+
+    assert_eq!(Byte::B as u8, 1 << 7);
+    assert_eq!(size_of::<Wide>(), 65536 / 8);
+    assert_eq!(Wide::FULL.into_iter().last(), Some(WideItem::B));
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 200)]
+        B,
+        C,
+    }
+
+    assert_eq!(Item::B as u8, 200);
+    assert_eq!(Item::C as u8, 201);
+    assert_eq!(Set::FULL.len(), 3);
+    assert_eq!(Set::FULL.without(Item::B), Set::from([Item::A, Item::C]));
+    assert_eq!(Set::FULL.into_iter().collect::<Vec<_>>(), Vec::from([Item::A, Item::B, Item::C]));
+}
@@ -0,0 +1,57 @@
+use flagnum::{Flags, UnknownBits};
+
+#[test]
+fn roundtrip() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    for set in [Set::EMPTY, Set::FULL, Set::from([Item::A, Item::C])] {
+        assert_eq!(Set::from_bits(set.bits()), Ok(set));
+        assert_eq!(Set::from_bits_truncate(set.bits()), set);
+    }
+}
+
+#[test]
+fn unknown() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 4)]
+        B,
+    }
+
+    let error = Set::from_bits(0b1111_0011).unwrap_err();
+    assert_eq!(error.bits(), 0b1110_0010);
+    assert_eq!(error.to_string(), "unknown set bits 226");
+    assert_eq!(Set::from_bits_truncate(0b1111_0011), Set::FULL);
+}
+
+#[test]
+fn const_fns() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    const BITS: u8 = Set::from_items(&[Item::A, Item::C]).bits();
+    const SET: Set = Set::from_bits_truncate(0b1110);
+    const UNKNOWN: Result<Set, UnknownBits<u8>> = Set::from_bits(0b1000);
+
+    assert_eq!(BITS, 0b101);
+    assert_eq!(SET, Set::from([Item::B, Item::C]));
+    assert_eq!(UNKNOWN.unwrap_err().bits(), 0b1000);
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 130)]
+        B,
+    }
+
+    let bits: [u64; 3] = Set::FULL.bits();
+    assert_eq!(bits, [1, 0, 1 << 2]);
+    assert_eq!(Set::from_bits(bits), Ok(Set::FULL));
+    assert_eq!(Set::from_bits([3, 0, 4]).unwrap_err().bits(), [2, 0, 0]);
+    assert_eq!(Set::from_bits_truncate([u64::MAX; 3]), Set::FULL);
+}
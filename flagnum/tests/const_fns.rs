@@ -0,0 +1,67 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Set, groups(GROUP))]
+enum Item {
+    A,
+    #[groups(GROUP)]
+    B,
+    #[groups(GROUP)]
+    C,
+}
+
+const A: Set = Set::from_item(Item::A);
+const NOT_GROUP: Set = Set::FULL.const_without(Set::GROUP);
+
+const _: () = assert!(NOT_GROUP.const_contains(A));
+const _: () = assert!(! NOT_GROUP.const_has_overlap(Set::GROUP));
+const _: () = assert!(NOT_GROUP.missing().const_contains(Set::GROUP));
+
+#[test]
+fn functional() {
+    const OVERLAP: Set = Set::FULL.const_overlap(Set::GROUP);
+    const WITH: Set = A.const_with(Set::GROUP);
+    const WITHOUT: Set = Set::FULL.const_without(A);
+    const MISSING: Set = A.missing();
+
+    assert_eq!(NOT_GROUP, A);
+    assert_eq!(OVERLAP, Set::GROUP);
+    assert_eq!(WITH, Set::FULL);
+    assert_eq!(WITHOUT, Set::GROUP);
+    assert_eq!(MISSING, Set::GROUP);
+}
+
+#[test]
+fn self_modifying() {
+    const SET: Set = {
+        let mut set = Set::EMPTY;
+        set.const_insert(Set::FULL);
+        set.const_remove(A);
+        set.const_keep(Set::from_item(Item::B));
+        set.invert();
+        set
+    };
+
+    assert_eq!(SET, Set::from([Item::A, Item::C]));
+}
+
+#[test]
+fn trait_methods() {
+    let mut set = Set::EMPTY;
+    set.insert(Item::A);
+    set.invert();
+    assert_eq!(set, Set::GROUP);
+    assert_eq!(set.missing(), A);
+    assert!(set.contains(Item::B));
+    assert_eq!(set.with(Item::A), Set::FULL);
+}
+
+#[test]
+fn migration() {
+    // `Set::FULL.without(Set::GROUP)` and `contains` are trait methods and can't be
+    // used in `const` items. The inherent `const_` variants take concrete sets.
+    const WITHOUT: Set = Set::FULL.const_without(Set::GROUP);
+    const CONTAINS: bool = Set::FULL.const_contains(Set::from_item(Item::B));
+
+    assert_eq!(WITHOUT, Set::FULL.without(Set::GROUP));
+    assert_eq!(CONTAINS, Set::FULL.contains(Item::B));
+}
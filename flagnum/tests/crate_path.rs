@@ -0,0 +1,66 @@
+// Local items shadowing the names used by generated code.
+#[allow(dead_code)]
+mod flagnum {}
+#[allow(dead_code)]
+mod std {}
+#[allow(dead_code)]
+mod core {}
+#[allow(dead_code)]
+struct Option;
+#[allow(dead_code)]
+struct Result;
+
+mod facade {
+    pub mod reexport {
+        pub use ::flagnum::*;
+    }
+}
+
+use ::flagnum::{Flag, Flags};
+
+#[facade::reexport::flag(
+    Days,
+    crate = facade::reexport,
+    groups(pub WEEKEND),
+    group_enum(DayGroup, DayGroups),
+    item_accessors,
+)]
+enum Day {
+    Monday,
+    #[groups(WEEKEND)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[::flagnum::flag(Large)]
+enum LargeItem {
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15,
+    B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11, B12, B13, B14, B15,
+    C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12, C13, C14, C15,
+    D0, D1, D2, D3, D4, D5, D6, D7, D8, D9, D10, D11, D12, D13, D14, D15,
+    E0, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14, E15,
+    F0, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15,
+    G0, G1, G2, G3, G4, G5, G6, G7, G8, G9, G10, G11, G12, G13, G14, G15,
+    H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15,
+    Last,
+}
+
+#[test]
+fn facade_path() {
+    let days = Day::Monday | Days::WEEKEND;
+    assert_eq!(days, Days::FULL);
+    assert_eq!(Day::Sunday.index(), 2);
+    assert_eq!(days.to_string(), "Monday|Saturday|Sunday");
+    assert_eq!("Monday|WEEKEND".parse(), Ok(days));
+    assert_eq!(Days::SATURDAY.complete_groups(), DayGroups::EMPTY);
+    assert_eq!(days.complete_groups(), DayGroups::from(DayGroup::Weekend));
+}
+
+#[test]
+fn shadowed_names() {
+    let set = LargeItem::Last | LargeItem::A0;
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), [LargeItem::A0, LargeItem::Last]);
+    assert_eq!(LargeItem::from_name("H15"), Some(LargeItem::H15));
+}
@@ -0,0 +1,69 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Days, groups(pub WEEKEND, pub ALL, pub NONE))]
+enum Day {
+    #[groups(ALL)]
+    Monday,
+    #[groups(ALL)]
+    Tuesday,
+    #[groups(WEEKEND, ALL)]
+    Saturday,
+    #[groups(WEEKEND, ALL)]
+    Sunday,
+}
+
+#[test]
+fn collapse() {
+    let days = Days::from([Day::Monday, Day::Saturday, Day::Sunday]);
+    assert_eq!(format!("{:?}", days.display_grouped()), "{WEEKEND, Monday}");
+    assert_eq!(days.display_grouped().to_string(), "{WEEKEND, Monday}");
+    assert_eq!(
+        format!("{:#?}", days.display_grouped()),
+        "{\n    WEEKEND,\n    Monday,\n}",
+    );
+}
+
+#[test]
+fn partial_and_empty() {
+    assert_eq!(format!("{:?}", Days::EMPTY.display_grouped()), "{}");
+    let days = Days::from([Day::Tuesday, Day::Sunday]);
+    assert_eq!(format!("{:?}", days.display_grouped()), "{Tuesday, Sunday}");
+}
+
+#[test]
+fn covered_groups() {
+    assert_eq!(format!("{:?}", Days::FULL.display_grouped()), "{ALL}");
+    let days = Days::from([Day::Tuesday, Day::Saturday, Day::Sunday]);
+    assert_eq!(format!("{:?}", days.display_grouped()), "{WEEKEND, Tuesday}");
+}
+
+#[test]
+fn unknown_bits() {
+    #[flagnum::flag(Set, groups(pub BOTH), retain_unknown_bits)]
+    enum Item {
+        #[groups(BOTH)]
+        A,
+        #[groups(BOTH)]
+        B,
+        C,
+    }
+
+    let set = Set::from_bits_retain(0b1011);
+    assert_eq!(format!("{:?}", set.display_grouped()), "{BOTH, UnknownBits { bits: 8 }}");
+}
+
+#[test]
+fn private_groups() {
+    #[flagnum::flag(Set, groups(PRIVATE, pub(crate) EXPOSED))]
+    enum Item {
+        #[groups(PRIVATE)]
+        A,
+        #[groups(PRIVATE, EXPOSED)]
+        B,
+        #[groups(EXPOSED)]
+        C,
+    }
+
+    assert_eq!(format!("{:?}", Set::FULL.display_grouped()), "{EXPOSED, A}");
+    assert_eq!(format!("{:?}", Set::from([Item::A, Item::B]).display_grouped()), "{A, B}");
+}
@@ -0,0 +1,83 @@
+use flagnum::{ExclusiveGroupError, Flags};
+
+#[flagnum::flag(Options, groups(pub exclusive LEVEL, pub exclusive MODE, pub OUTPUT))]
+enum Opt {
+    Color,
+    #[groups(LEVEL, OUTPUT)]
+    Quiet,
+    #[groups(LEVEL, OUTPUT)]
+    Verbose,
+    #[groups(LEVEL, MODE)]
+    Trace,
+    #[groups(MODE)]
+    Batch,
+}
+
+#[test]
+fn set_exclusive() {
+    let mut options = Options::from([Opt::Color, Opt::Quiet]);
+    options.set_exclusive(Opt::Verbose);
+    assert_eq!(options, Options::from([Opt::Color, Opt::Verbose]));
+
+    let mut options = Options::from([Opt::Quiet, Opt::Batch]);
+    options.set_exclusive(Opt::Trace);
+    assert_eq!(options, Options::from(Opt::Trace));
+
+    let mut options = Options::from([Opt::Quiet, Opt::Verbose]);
+    options.set_exclusive(Opt::Color);
+    assert_eq!(options, Options::from([Opt::Color, Opt::Quiet, Opt::Verbose]));
+}
+
+#[test]
+fn selected_in() {
+    let options = Options::from([Opt::Color, Opt::Verbose]);
+    assert_eq!(options.selected_in(Options::LEVEL), Some(Opt::Verbose));
+    assert_eq!(options.selected_in(Options::MODE), None);
+    assert_eq!(Options::OUTPUT.selected_in(Options::LEVEL), None);
+    assert_eq!(options.selected_in(Options::OUTPUT), Some(Opt::Verbose));
+}
+
+#[test]
+fn validate_exclusive() {
+    assert_eq!(Options::EMPTY.validate_exclusive(), Ok(()));
+    assert_eq!(Options::from([Opt::Color, Opt::Quiet]).validate_exclusive(), Ok(()));
+    assert_eq!(Options::OUTPUT.validate_exclusive(), Err(ExclusiveGroupError::new("LEVEL", Options::OUTPUT)));
+
+    let error = Options::from([Opt::Color, Opt::Trace, Opt::Batch]).validate_exclusive().unwrap_err();
+    assert_eq!(error.group(), "MODE");
+    assert_eq!(error.selected(), Options::from([Opt::Trace, Opt::Batch]));
+    assert_eq!(error.to_string(), "multiple items {Trace, Batch} selected in exclusive group MODE");
+}
+
+#[test]
+fn const_fns() {
+    const OPTIONS: Options = {
+        let mut options = Options::from_item(Opt::Quiet);
+        options.set_exclusive(Opt::Verbose);
+        options
+    };
+    const SELECTED: Option<Opt> = OPTIONS.selected_in(Options::LEVEL);
+    const VALID: Result<(), ExclusiveGroupError<Options>> = OPTIONS.validate_exclusive();
+
+    assert_eq!(SELECTED, Some(Opt::Verbose));
+    assert_eq!(VALID, Ok(()));
+}
+
+#[test]
+fn exclusive_group_name() {
+    #[flagnum::flag(Set, groups(
+        #[allow(non_upper_case_globals)]
+        pub exclusive,
+        pub exclusive OTHER,
+    ))]
+    enum Item {
+        #[groups(exclusive, OTHER)]
+        A,
+        #[groups(OTHER)]
+        B,
+    }
+
+    assert_eq!(Set::exclusive, Set::from(Item::A));
+    assert_eq!(Set::from(Item::A).validate_exclusive(), Ok(()));
+    assert_eq!(Set::FULL.validate_exclusive().unwrap_err().group(), "OTHER");
+}
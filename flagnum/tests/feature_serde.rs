@@ -22,4 +22,152 @@ fn de() {
     assert_eq!(serde_json::from_str::<Set>("[]").unwrap(), Set::EMPTY);
     assert!(serde_json::from_str::<Set>("[0]").is_err());
     assert!(serde_json::from_str::<Set>("{}").is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn roundtrip_words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+        A1, B1, C1, D1, E1, F1, G1, H1, I1, J1, K1, L1, M1, N1, O1, P1, Q1, R1, S1, T1,
+        U1, V1, W1, X1, Y1, Z1, A2, B2, C2, D2, E2, F2, G2, H2, I2, J2, K2, L2, M2, N2,
+        O2, P2, Q2, R2, S2, T2, U2, V2, W2, X2, Y2, Z2, A3, B3, C3, D3, E3, F3, G3, H3,
+        I3, J3, K3, L3, M3, N3, O3, P3, Q3, R3, S3, T3, U3, V3, W3, X3, Y3, Z3, A4, B4,
+        C4, D4, E4, F4, G4, H4, I4, J4, K4, L4, M4, N4, O4, P4, Q4, R4, S4, T4, U4, V4,
+        W4, X4, Y4, Z4, A5, B5, C5, D5, E5, F5, G5, H5, I5, J5, K5, L5, M5, N5, O5, P5,
+    }
+
+    let set = Set::from([Item::A, Item::P5]);
+    let contents = serde_json::to_string(&set).unwrap();
+    assert_eq!(contents, r#"["A","P5"]"#);
+
+    let set_rt: Set = serde_json::from_str(&contents).unwrap();
+    assert_eq!(set, set_rt);
+}
+
+#[test]
+fn retain_unknown_bits() {
+    #[flagnum::flag(Set, retain_unknown_bits)]
+    enum Item { A, B, C }
+
+    let set = Set::from_bits_retain(0b1000_0010);
+    let contents = serde_json::to_string(&set).unwrap();
+    assert_eq!(contents, r#"[["B"],128]"#);
+
+    let set_rt: Set = serde_json::from_str(&contents).unwrap();
+    assert_eq!(set, set_rt);
+
+    assert_eq!(serde_json::to_string(&Set::from(Item::C)).unwrap(), r#"[["C"],0]"#);
+    assert_eq!(serde_json::from_str::<Set>("[[],0]").unwrap(), Set::EMPTY);
+    assert!(serde_json::from_str::<Set>(r#"[["D"],0]"#).is_err());
+    assert!(serde_json::from_str::<Set>(r#"[["A"]]"#).is_err());
+    assert!(serde_json::from_str::<Set>(r#"["A"]"#).is_err());
+}
+
+#[test]
+fn retain_unknown_bits_binary() {
+    #[flagnum::flag(Set, retain_unknown_bits)]
+    enum Item { A, B, C }
+
+    let set = Set::from_bits_retain(0b1000_0101);
+    let contents = bincode::serialize(&set).unwrap();
+    assert_eq!(bincode::deserialize::<Set>(&contents).unwrap(), set);
+
+    #[flagnum::flag(Words, retain_unknown_bits)]
+    enum Word {
+        A,
+        #[flagnum(bit = 130)]
+        B,
+    }
+
+    let words = Words::from_bits_retain([0b11, 0, 0b101]);
+    let contents = bincode::serialize(&words).unwrap();
+    assert_eq!(bincode::deserialize::<Words>(&contents).unwrap(), words);
+}
+
+#[test]
+fn retain_unknown_bits_words() {
+    #[flagnum::flag(Set, retain_unknown_bits)]
+    enum Item {
+        A,
+        #[flagnum(bit = 130)]
+        B,
+    }
+
+    let set = Set::from_bits_retain([0b10, 0, 0b101]);
+    let contents = serde_json::to_string(&set).unwrap();
+    assert_eq!(contents, r#"[["B"],[2,0,1]]"#);
+    assert_eq!(serde_json::from_str::<Set>(&contents).unwrap(), set);
+}
+
+#[test]
+fn rename_all() {
+    #[flagnum::flag(Set, rename_all = "snake_case")]
+    enum Item { FirstItem, SecondItem }
+
+    let set = Set::from([Item::FirstItem, Item::SecondItem]);
+    let contents = serde_json::to_string(&set).unwrap();
+    assert_eq!(contents, r#"["first_item","second_item"]"#);
+    assert_eq!(serde_json::from_str::<Set>(&contents).unwrap(), set);
+}
+
+#[test]
+fn group_enum() {
+    #[flagnum::flag(Set, groups(pub LOW, pub HIGH), group_enum(Group, Groups))]
+    enum Item {
+        #[groups(LOW)]
+        A,
+        #[groups(HIGH)]
+        B,
+    }
+
+    let groups = Set::FULL.complete_groups();
+    let contents = serde_json::to_string(&groups).unwrap();
+    assert_eq!(contents, r#"["LOW","HIGH"]"#);
+    assert_eq!(serde_json::from_str::<Groups>(&contents).unwrap(), groups);
+}
+
+#[test]
+fn skip_derives() {
+    #[flagnum::flag(Set, skip_item_derives(Serialize), skip_set_derives(Deserialize))]
+    enum Item { A, B }
+
+    impl flagnum::feature_serde::dep::Serialize for Item {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: flagnum::feature_serde::dep::Serializer,
+        {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+
+    assert_eq!(serde_json::to_string(&Set::FULL).unwrap(), "[1,2]");
+    assert_eq!(serde_json::from_str::<Item>(r#""B""#).unwrap(), Item::B);
+}
+
+#[test]
+fn crate_path() {
+    mod facade {
+        pub use flagnum as inner;
+    }
+
+    #[facade::inner::flag(Set, crate = facade::inner)]
+    enum Item { A, B }
+
+    assert_eq!(serde_json::to_string(&Set::FULL).unwrap(), r#"["A","B"]"#);
+    assert_eq!(serde_json::from_str::<Set>(r#"["B"]"#).unwrap(), Set::from(Item::B));
+}
+
+#[test]
+fn explicit_names() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(name = "second")]
+        B,
+    }
+
+    let contents = serde_json::to_string(&Set::FULL).unwrap();
+    assert_eq!(contents, r#"["A","second"]"#);
+    assert_eq!(serde_json::from_str::<Set>(&contents).unwrap(), Set::FULL);
+}
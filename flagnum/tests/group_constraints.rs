@@ -0,0 +1,35 @@
+use flagnum::Flags;
+
+#[flagnum::flag(
+    Days,
+    groups(WEEKEND, WORKDAYS = !WEEKEND, MONDAY = Monday, LATE = Tuesday | Saturday),
+    partition(WEEKEND, WORKDAYS),
+    partition(MONDAY, Tuesday, WEEKEND),
+    disjoint(MONDAY, LATE),
+    disjoint(WEEKEND),
+    covers(WORKDAYS | WEEKEND),
+    covers(FULL),
+)]
+enum Day {
+    Monday,
+    Tuesday,
+    #[groups(WEEKEND)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[test]
+fn constraints_hold() {
+    assert!(Days::WEEKEND.is_disjoint(Days::WORKDAYS));
+    assert_eq!(Days::WEEKEND.with(Days::WORKDAYS), Days::FULL);
+    assert!(Days::MONDAY.is_disjoint(Days::LATE));
+}
+
+#[test]
+fn empty_enum() {
+    #[flagnum::flag(Set, groups(NONE), partition(NONE), covers())]
+    enum Item {}
+
+    assert!(Set::NONE.is_empty());
+}
@@ -0,0 +1,84 @@
+use flagnum::{Flag, Flags};
+
+#[flagnum::flag(
+    Days,
+    groups(pub WEEKEND, pub WORKDAYS = !WEEKEND, pub LONG_WEEKEND = WEEKEND | Monday, PRIVATE),
+    group_enum(DayGroup, DayGroups),
+)]
+enum Day {
+    Monday,
+    Tuesday,
+    #[groups(WEEKEND, PRIVATE)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[test]
+fn group_items() {
+    assert_eq!(DayGroups::ITEMS, &[DayGroup::Weekend, DayGroup::Workdays, DayGroup::LongWeekend]);
+    assert_eq!(DayGroup::NAMES, &["WEEKEND", "WORKDAYS", "LONG_WEEKEND"]);
+    for (&group, &(name, set)) in DayGroups::ITEMS.iter().zip(Days::GROUPS) {
+        assert_eq!(group.name(), name);
+        assert_eq!(group.items(), set);
+    }
+}
+
+#[test]
+fn group_set() {
+    assert_eq!(Day::Monday.group_set(), DayGroups::from([DayGroup::Workdays, DayGroup::LongWeekend]));
+    assert_eq!(Day::Tuesday.group_set(), DayGroups::from(DayGroup::Workdays));
+    assert_eq!(Day::Sunday.group_set(), DayGroups::from([DayGroup::Weekend, DayGroup::LongWeekend]));
+    for &day in Days::ITEMS {
+        let groups: Vec<_> = day.groups().map(|(name, _)| name).collect();
+        let group_set: Vec<_> = day.group_set().into_iter().map(DayGroup::name).collect();
+        assert_eq!(groups, group_set);
+    }
+}
+
+#[test]
+fn complete_and_touched() {
+    let days = Days::from([Day::Monday, Day::Saturday, Day::Sunday]);
+    assert_eq!(days.complete_groups(), DayGroups::from([DayGroup::Weekend, DayGroup::LongWeekend]));
+    assert_eq!(days.touched_groups(), DayGroups::FULL);
+
+    let days = Days::from(Day::Saturday);
+    assert_eq!(days.complete_groups(), DayGroups::EMPTY);
+    assert_eq!(days.touched_groups(), DayGroups::from([DayGroup::Weekend, DayGroup::LongWeekend]));
+
+    assert_eq!(Days::EMPTY.complete_groups(), DayGroups::EMPTY);
+    assert_eq!(Days::EMPTY.touched_groups(), DayGroups::EMPTY);
+    assert_eq!(Days::FULL.complete_groups(), DayGroups::FULL);
+}
+
+#[test]
+fn const_fns() {
+    const COMPLETE: DayGroups = Days::WEEKEND.complete_groups();
+    const GROUPS: DayGroups = Day::Tuesday.group_set();
+
+    assert_eq!(COMPLETE, DayGroups::from(DayGroup::Weekend));
+    assert_eq!(GROUPS, DayGroups::from(DayGroup::Workdays));
+}
+
+#[test]
+fn no_groups() {
+    #[flagnum::flag(Set, group_enum(Group, Groups))]
+    enum Item { A }
+
+    assert!(Groups::ITEMS.is_empty());
+    assert_eq!(Item::A.group_set(), Groups::EMPTY);
+    assert_eq!(Set::FULL.complete_groups(), Groups::EMPTY);
+}
+
+#[test]
+#[allow(non_upper_case_globals)]
+fn group_names() {
+    #[flagnum::flag(Set, groups(pub weekend, pub Work, pub r#LATE), group_enum(Group, Groups))]
+    enum Item { A }
+
+    assert_eq!(Groups::ITEMS, &[Group::Weekend, Group::Work, Group::Late]);
+    assert_eq!(Group::NAMES, &["weekend", "Work", "LATE"]);
+    for (&group, &(name, _)) in Groups::ITEMS.iter().zip(Set::GROUPS) {
+        assert_eq!(group.name(), name);
+    }
+}
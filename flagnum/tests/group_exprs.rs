@@ -0,0 +1,59 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Days, groups(
+    pub WEEKEND,
+    pub WORKDAYS = !WEEKEND,
+    pub ALL_BUT_MONDAY = FULL - Monday,
+    pub MIDWEEK = WORKDAYS - (Monday | Friday),
+    pub NOTHING = EMPTY,
+    pub EDGES = Monday | Friday | Sunday,
+    pub EDGE_WORKDAYS = EDGES & WORKDAYS,
+    pub TOGGLED = EDGES ^ WEEKEND,
+    pub FORWARD = LATER,
+    pub LATER = Tuesday,
+))]
+enum Day {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    #[groups(WEEKEND)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[test]
+fn complement() {
+    assert_eq!(Days::WORKDAYS, Days::WEEKEND.missing());
+    assert_eq!(Days::ALL_BUT_MONDAY, Days::FULL.without(Day::Monday));
+}
+
+#[test]
+fn operators() {
+    assert_eq!(Days::MIDWEEK, Days::from([Day::Tuesday, Day::Wednesday, Day::Thursday]));
+    assert_eq!(Days::NOTHING, Days::EMPTY);
+    assert_eq!(Days::EDGE_WORKDAYS, Days::from([Day::Monday, Day::Friday]));
+    assert_eq!(Days::TOGGLED, Days::from([Day::Monday, Day::Friday, Day::Saturday]));
+}
+
+#[test]
+fn forward_reference() {
+    assert_eq!(Days::FORWARD, Days::from(Day::Tuesday));
+    assert_eq!(Days::LATER, Days::from(Day::Tuesday));
+}
+
+#[test]
+fn bit_positions() {
+    #[flagnum::flag(Set, groups(pub ODD = !EVEN, pub EVEN = A | C))]
+    enum Item {
+        A,
+        #[flagnum(bit = 70)]
+        B,
+        C,
+    }
+
+    assert_eq!(Set::ODD, Set::from(Item::B));
+    assert_eq!(Set::EVEN, Set::from([Item::A, Item::C]));
+}
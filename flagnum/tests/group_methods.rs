@@ -0,0 +1,71 @@
+use flagnum::Flags;
+
+mod days {
+    use flagnum::Flags;
+
+    #[flagnum::flag(
+        pub Days,
+        groups(pub WEEKEND, pub LONG_WEEKEND = WEEKEND | Monday, HIDDEN),
+        group_methods,
+    )]
+    pub enum Day {
+        Monday,
+        Tuesday,
+        #[groups(WEEKEND, HIDDEN)]
+        Saturday,
+        #[groups(WEEKEND)]
+        Sunday,
+    }
+
+    #[test]
+    fn private_group() {
+        assert!(Days::FULL.contains_all_hidden());
+        assert_eq!(Days::FULL.hidden_part(), Days::HIDDEN);
+    }
+}
+
+use days::{Day, Days};
+
+#[test]
+fn predicates() {
+    let days = Days::from([Day::Monday, Day::Saturday]);
+    assert!(days.contains_any_weekend());
+    assert!(!days.contains_all_weekend());
+    assert!(days.contains_any_long_weekend());
+    assert!(Days::FULL.contains_all_long_weekend());
+    assert!(!Days::from(Day::Tuesday).contains_any_weekend());
+}
+
+#[test]
+fn part_and_clear() {
+    let mut days = Days::from([Day::Monday, Day::Tuesday, Day::Saturday]);
+    assert_eq!(days.weekend_part(), Days::from(Day::Saturday));
+    assert_eq!(days.long_weekend_part(), Days::from([Day::Monday, Day::Saturday]));
+
+    days.clear_long_weekend();
+    assert_eq!(days, Days::from(Day::Tuesday));
+}
+
+#[test]
+fn const_fns() {
+    const PART: Days = Days::FULL.weekend_part();
+    const CLEARED: Days = {
+        let mut days = Days::FULL;
+        days.clear_weekend();
+        days
+    };
+
+    assert_eq!(PART, Days::WEEKEND);
+    assert_eq!(CLEARED, Days::from([Day::Monday, Day::Tuesday]));
+}
+
+#[test]
+fn cfg() {
+    #[flagnum::flag(Set, groups(#[cfg(any())] pub DISABLED, pub ENABLED), group_methods)]
+    enum Item {
+        #[groups(ENABLED)]
+        A,
+    }
+
+    assert!(Set::FULL.contains_all_enabled());
+}
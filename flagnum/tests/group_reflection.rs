@@ -0,0 +1,55 @@
+use flagnum::{Flag, Flags};
+
+#[flagnum::flag(Set, groups(pub LOW, pub(crate) HIGH, PRIVATE, pub EMPTY))]
+enum Item {
+    #[groups(LOW, PRIVATE)]
+    A,
+    #[groups(LOW, HIGH)]
+    B,
+    #[groups(HIGH)]
+    C,
+}
+
+#[test]
+fn groups() {
+    assert_eq!(
+        Set::GROUPS,
+        &[("LOW", Set::LOW), ("HIGH", Set::HIGH), ("EMPTY", Set::EMPTY)],
+    );
+}
+
+#[test]
+fn item_groups() {
+    let names = |item: Item| item.groups().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names(Item::A), ["LOW"]);
+    assert_eq!(names(Item::B), ["LOW", "HIGH"]);
+    assert_eq!(names(Item::C), ["HIGH"]);
+    for &item in Set::ITEMS {
+        assert!(item.groups().all(|(_, group)| group.contains(item)));
+    }
+}
+
+#[test]
+fn no_groups() {
+    #[flagnum::flag(Set)]
+    enum Item { A }
+
+    assert!(Set::GROUPS.is_empty());
+    assert_eq!(Item::A.groups().next(), None);
+}
+
+#[test]
+fn cfg() {
+    #[flagnum::flag(Set, groups(
+        pub ENABLED,
+        #[cfg(any())]
+        pub DISABLED,
+    ))]
+    enum Item {
+        #[groups(ENABLED)]
+        A,
+        B,
+    }
+
+    assert_eq!(Set::GROUPS, &[("ENABLED", Set::ENABLED)]);
+}
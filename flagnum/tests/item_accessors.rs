@@ -0,0 +1,63 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Days, groups(pub WEEKEND), item_accessors)]
+enum Day {
+    Monday,
+    LateFriday,
+    #[groups(WEEKEND)]
+    Saturday,
+    #[groups(WEEKEND)]
+    Sunday,
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Days::MONDAY, Days::from(Day::Monday));
+    assert_eq!(Days::LATE_FRIDAY, Days::from(Day::LateFriday));
+    assert_eq!(Days::SATURDAY.with(Days::SUNDAY), Days::WEEKEND);
+}
+
+#[test]
+fn accessors() {
+    let mut days = Days::EMPTY;
+    assert!(!days.monday());
+
+    days.set_monday(true);
+    days.set_late_friday(true);
+    assert!(days.monday());
+    assert!(days.late_friday());
+
+    days.set_monday(false);
+    days.set_late_friday(true);
+    assert_eq!(days, Days::LATE_FRIDAY);
+
+    days.toggle_sunday();
+    days.toggle_late_friday();
+    assert_eq!(days, Days::SUNDAY);
+}
+
+#[test]
+fn const_fns() {
+    const DAYS: Days = {
+        let mut days = Days::WEEKEND;
+        days.set_saturday(false);
+        days.toggle_monday();
+        days
+    };
+    const HAS_MONDAY: bool = DAYS.monday();
+
+    assert_eq!(DAYS, Days::from([Day::Monday, Day::Sunday]));
+    assert_eq!(HAS_MONDAY, DAYS.contains(Day::Monday));
+}
+
+#[test]
+fn lowercase() {
+    #[flagnum::flag(Set, item_accessors = "lowercase")]
+    enum Item { LateFriday, r#Type }
+
+    let mut set = Set::LATE_FRIDAY;
+    set.set_type(true);
+    assert!(set.latefriday());
+    assert!(set.r#type());
+    assert_eq!(set, Set::TYPE.with(Set::LATE_FRIDAY));
+}
@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use flagnum::{Flag, Flags, ParseItemError};
+
+#[test]
+fn names() {
+    #[flagnum::flag(Set)]
+    enum Item { Alpha, BetaGamma, r#Delta }
+
+    assert_eq!(Item::NAMES, &["Alpha", "BetaGamma", "Delta"]);
+    for (&item, &name) in Set::ITEMS.iter().zip(Item::NAMES) {
+        assert_eq!(item.name(), name);
+        assert_eq!(item.to_string(), name);
+        assert_eq!(Item::from_name(name), Some(item));
+        assert_eq!(Item::from_str(name), Ok(item));
+    }
+    assert_eq!(format!("[{:>7}]", Item::Alpha), "[  Alpha]");
+}
+
+#[test]
+fn unknown_name() {
+    #[flagnum::flag(Set)]
+    enum Item { Alpha, Beta }
+
+    assert_eq!(Item::from_name("alpha"), None);
+    assert_eq!(Item::from_name(""), None);
+}
+
+#[test]
+fn parse_error() {
+    #[flagnum::flag(Set)]
+    enum Item { Alpha, Beta }
+
+    let error = "Gamma".parse::<Item>().unwrap_err();
+    assert_eq!(error, ParseItemError::new());
+    assert_eq!(error.to_string(), "unknown item name");
+}
+
+#[test]
+fn rename_all() {
+    #[flagnum::flag(Snake, rename_all = "snake_case")]
+    enum SnakeItem { Plain, TwoWords, HTTPServer, Version2Api }
+
+    #[flagnum::flag(Kebab, rename_all = "kebab-case")]
+    enum KebabItem { Plain, TwoWords, HTTPServer, Version2Api }
+
+    #[flagnum::flag(Screaming, rename_all = "SCREAMING_SNAKE_CASE")]
+    enum ScreamingItem { Plain, TwoWords, HTTPServer, Version2Api }
+
+    #[flagnum::flag(Verbatim, rename_all = "verbatim")]
+    enum VerbatimItem { Plain, TwoWords, HTTPServer, r#Version2Api }
+
+    assert_eq!(
+        SnakeItem::NAMES,
+        &["plain", "two_words", "http_server", "version2_api"],
+    );
+    assert_eq!(
+        KebabItem::NAMES,
+        &["plain", "two-words", "http-server", "version2-api"],
+    );
+    assert_eq!(
+        ScreamingItem::NAMES,
+        &["PLAIN", "TWO_WORDS", "HTTP_SERVER", "VERSION2_API"],
+    );
+    assert_eq!(
+        VerbatimItem::NAMES,
+        &["Plain", "TwoWords", "HTTPServer", "Version2Api"],
+    );
+
+    assert_eq!(SnakeItem::HTTPServer.to_string(), "http_server");
+    assert_eq!("two-words".parse(), Ok(KebabItem::TwoWords));
+    assert!("TwoWords".parse::<KebabItem>().is_err());
+}
+
+#[test]
+fn explicit_names() {
+    #[flagnum::flag(Set, rename_all = "snake_case")]
+    enum Item {
+        Alpha,
+        #[flagnum(name = "BETA")]
+        Beta,
+        #[flagnum(name = "gamma ray")]
+        Gamma,
+    }
+
+    assert_eq!(Item::NAMES, &["alpha", "BETA", "gamma ray"]);
+    assert_eq!(Item::from_name("BETA"), Some(Item::Beta));
+    assert_eq!(Item::from_name("beta"), None);
+    assert_eq!(Item::Gamma.to_string(), "gamma ray");
+}
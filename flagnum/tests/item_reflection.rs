@@ -0,0 +1,114 @@
+use flagnum::{Flag, Flags};
+
+#[test]
+fn index() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    assert_eq!(Item::COUNT, 3);
+    for (index, &item) in Set::ITEMS.iter().enumerate() {
+        assert_eq!(item.index(), index);
+        assert_eq!(Item::from_index(index), Some(item));
+    }
+    assert_eq!(Item::from_index(Item::COUNT), None);
+}
+
+#[test]
+fn bit() {
+    #[flagnum::flag(Set, keep_discriminants)]
+    enum Item {
+        A = 10,
+        #[flagnum(bit = 6)]
+        B = 5,
+        C = 0,
+    }
+
+    assert_eq!([Item::A.bit(), Item::B.bit(), Item::C.bit()], [0, 6, 7]);
+    assert_eq!([Item::A.index(), Item::B.index(), Item::C.index()], [0, 1, 2]);
+    for &item in Set::ITEMS {
+        assert_eq!(Item::from_bit(item.bit()), Some(item));
+        assert_eq!(Set::from_bits(1 << item.bit()), Ok(item.into()));
+    }
+    assert_eq!(Item::from_bit(1), None);
+    assert_eq!(Item::from_bit(8), None);
+}
+
+#[test]
+fn generic() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    fn count_items<T>(items: &[T]) -> Vec<usize>
+    where
+        T: Flag,
+    {
+        let mut counts = vec![0; T::COUNT];
+        for item in items {
+            counts[item.index()] += 1;
+        }
+        counts
+    }
+
+    assert_eq!(count_items(&[Item::A, Item::C, Item::A]), [2, 0, 1]);
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 190)]
+        B,
+    }
+
+    assert_eq!(Item::COUNT, 2);
+    assert_eq!(Item::B.bit(), 190);
+    assert_eq!(Item::B.index(), 1);
+    assert_eq!(Item::from_bit(190), Some(Item::B));
+}
+
+#[test]
+fn const_fns() {
+    #[flagnum::flag(Set, item_const_fns)]
+    enum Item {
+        A,
+        #[flagnum(bit = 4)]
+        B,
+        C,
+    }
+
+    const INDEX: usize = Item::C.index();
+    const FROM_INDEX: Option<Item> = Item::from_index(1);
+    const BIT: usize = Item::B.bit();
+    const FROM_BIT: Option<Item> = Item::from_bit(5);
+    const COUNTS: [u32; Item::COUNT] = {
+        let mut counts = [0; Item::COUNT];
+        counts[Item::B.index()] += 1;
+        counts
+    };
+
+    assert_eq!(INDEX, 2);
+    assert_eq!(FROM_INDEX, Some(Item::B));
+    assert_eq!(BIT, 4);
+    assert_eq!(FROM_BIT, Some(Item::C));
+    assert_eq!(COUNTS, [0, 1, 0]);
+}
+
+#[test]
+fn own_inherent_methods() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B }
+
+    impl Item {
+        fn index(self) -> &'static str {
+            match self {
+                Self::A => "first",
+                Self::B => "second",
+            }
+        }
+    }
+
+    assert_eq!(Item::B.index(), "second");
+    assert_eq!(Flag::index(Item::B), 1);
+    assert_eq!(Set::ITEMS[Flag::index(Item::B)], Item::B);
+}
@@ -0,0 +1,97 @@
+use flagnum::{Flags, Iter};
+
+#[flagnum::flag(Set)]
+enum Item { A, B, C, D }
+
+#[test]
+fn double_ended() {
+    let set = Set::from([Item::A, Item::C, Item::D]);
+
+    assert_eq!(set.into_iter().rev().collect::<Vec<_>>(), Vec::from([Item::D, Item::C, Item::A]));
+
+    let mut iter = set.into_iter();
+    assert_eq!(iter.next(), Some(Item::A));
+    assert_eq!(iter.next_back(), Some(Item::D));
+    assert_eq!(iter.next_back(), Some(Item::C));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn exact_size() {
+    let mut iter = Set::from([Item::B, Item::C]).into_iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    iter.next();
+    assert_eq!(iter.len(), 1);
+    iter.next();
+    assert_eq!(iter.len(), 0);
+
+    assert_eq!(Set::FULL.into_iter().count(), 4);
+    assert_eq!(Set::FULL.into_iter().last(), Some(Item::D));
+    assert_eq!(Set::EMPTY.into_iter().last(), None);
+}
+
+#[test]
+fn fused() {
+    let mut iter = Set::from(Item::B).into_iter();
+    assert_eq!(iter.next(), Some(Item::B));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 63)]
+        B,
+        C,
+        #[flagnum(bit = 200)]
+        D,
+    }
+
+    let iter = Set::FULL.into_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.clone().collect::<Vec<_>>(), Vec::from([Item::A, Item::B, Item::C, Item::D]));
+    assert_eq!(iter.rev().collect::<Vec<_>>(), Vec::from([Item::D, Item::C, Item::B, Item::A]));
+}
+
+#[test]
+fn unknown_bits() {
+    #[flagnum::flag(Set, retain_unknown_bits)]
+    enum Item { A, B }
+
+    let iter = Iter::new(Set::from_bits_retain(0b1000_0010));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), Vec::from([Item::B]));
+}
+
+#[test]
+fn pinned_bits() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        #[flagnum(bit = 3)]
+        A,
+        #[flagnum(bit = 0)]
+        B,
+        C,
+        #[flagnum(bit = 2)]
+        D,
+    }
+
+    let set = Set::from([Item::A, Item::C, Item::D]);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), Vec::from([Item::A, Item::C, Item::D]));
+    assert_eq!(set.into_iter().rev().collect::<Vec<_>>(), Vec::from([Item::D, Item::C, Item::A]));
+
+    let mut iter = Set::FULL.into_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(Item::A));
+    assert_eq!(iter.next_back(), Some(Item::D));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(Item::C));
+    assert_eq!(iter.next(), Some(Item::B));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
@@ -0,0 +1,49 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Set, groups(GROUP), keep_discriminants)]
+#[repr(u16)]
+enum Item {
+    A = 300,
+    #[groups(GROUP)]
+    B = 7,
+    C,
+    #[flagnum(bit = 5)]
+    D = 1,
+}
+
+#[test]
+fn discriminants() {
+    assert_eq!(Item::A as u16, 300);
+    assert_eq!(Item::B as u16, 7);
+    assert_eq!(Item::C as u16, 8);
+    assert_eq!(Item::D as u16, 1);
+    assert_eq!(std::mem::size_of::<Set>(), 1);
+}
+
+#[test]
+fn sets() {
+    assert_eq!(Set::FULL.len(), 4);
+    assert_eq!(Set::GROUP, Item::B.into());
+    assert!(Set::from([Item::A, Item::B, Item::C, Item::D]).is_full());
+    assert!(Set::from_items(&[Item::A, Item::B, Item::C, Item::D]).is_full());
+    assert_eq!(Set::from_iter([Item::C, Item::D]), Set::from([Item::D, Item::C]));
+    assert_eq!(Set::from(Item::A).missing(), Set::from([Item::B, Item::C, Item::D]));
+    assert_eq!(
+        Set::FULL.into_iter().collect::<Vec<_>>(),
+        Vec::from([Item::A, Item::B, Item::C, Item::D]),
+    );
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set, keep_discriminants)]
+    enum Item {
+        A = 10,
+        #[flagnum(bit = 150)]
+        B = 20,
+    }
+
+    assert_eq!(Item::B as u8, 20);
+    assert_eq!(Set::FULL.len(), 2);
+    assert_eq!(Set::FULL.without(Item::A), Item::B.into());
+}
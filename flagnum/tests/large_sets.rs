@@ -0,0 +1,75 @@
+use flagnum::Flags;
+
+#[flagnum::flag(#[derive(Default)] Set, groups(EDGES))]
+enum Item {
+    #[groups(EDGES)]
+    V000,
+    V001, V002, V003, V004, V005, V006, V007, V008, V009, V010,
+    V011, V012, V013, V014, V015, V016, V017, V018, V019, V020,
+    V021, V022, V023, V024, V025, V026, V027, V028, V029, V030,
+    V031, V032, V033, V034, V035, V036, V037, V038, V039, V040,
+    V041, V042, V043, V044, V045, V046, V047, V048, V049, V050,
+    V051, V052, V053, V054, V055, V056, V057, V058, V059, V060,
+    V061, V062, V063, V064, V065, V066, V067, V068, V069, V070,
+    V071, V072, V073, V074, V075, V076, V077, V078, V079, V080,
+    V081, V082, V083, V084, V085, V086, V087, V088, V089, V090,
+    V091, V092, V093, V094, V095, V096, V097, V098, V099, V100,
+    V101, V102, V103, V104, V105, V106, V107, V108, V109, V110,
+    V111, V112, V113, V114, V115, V116, V117, V118, V119, V120,
+    V121, V122, V123, V124, V125, V126, V127, V128, V129, V130,
+    V131, V132, V133, V134, V135, V136, V137, V138, V139, V140,
+    V141, V142, V143, V144, V145, V146, V147, V148,
+    #[groups(EDGES)]
+    V149,
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Set::ITEMS.len(), 150);
+    assert_eq!(Set::FULL.len(), 150);
+    assert!(Set::FULL.is_full());
+    assert!(Set::EMPTY.is_empty());
+    assert!(Set::default().is_empty());
+    assert_eq!(Set::EDGES, Set::from([Item::V000, Item::V149]));
+}
+
+#[test]
+fn operations() {
+    let set = Set::from([Item::V001, Item::V064, Item::V130]);
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(Item::V130));
+    assert!(! set.contains(Item::V129));
+    assert!(Set::FULL.contains(set));
+    assert!(set.has_overlap(Item::V064));
+    assert!(! set.has_overlap(Set::EDGES));
+    assert_eq!(set.with(Set::EDGES).len(), 5);
+    assert_eq!(set.without(Item::V064), Set::from([Item::V001, Item::V130]));
+    assert_eq!(set.overlap(Set::from([Item::V064, Item::V149])), Item::V064.into());
+    assert_eq!(set.missing().len(), 147);
+    assert!(set.with(set.missing()).is_full());
+    assert_eq!(set.retained(|item| item != Item::V130), Set::from([Item::V001, Item::V064]));
+
+    let mut set = set;
+    set.insert(Item::V149);
+    set.remove(Item::V001);
+    set.keep(Set::from([Item::V064, Item::V149]));
+    assert_eq!(set, Set::from([Item::V064, Item::V149]));
+    set.invert();
+    assert_eq!(set.len(), 148);
+}
+
+#[test]
+fn iteration() {
+    let items = [Item::V000, Item::V063, Item::V064, Item::V128, Item::V149];
+    let set = Set::from(items);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), Vec::from(items));
+    assert_eq!(Set::FULL.into_iter().count(), 150);
+}
+
+#[test]
+fn const_fns() {
+    const SET: Set = Set::from_items(&[Item::V010, Item::V140]);
+    const LEN: usize = SET.len();
+    assert_eq!(LEN, 2);
+    assert_eq!(Set::from_sets(&[SET, Set::EDGES]).len(), 4);
+}
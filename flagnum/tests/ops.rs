@@ -0,0 +1,71 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Set)]
+enum Item { A, B, C }
+
+#[test]
+fn set_ops() {
+    let ab = Set::from([Item::A, Item::B]);
+    let bc = Set::from([Item::B, Item::C]);
+
+    assert_eq!(ab | bc, Set::FULL);
+    assert_eq!(ab | Item::C, Set::FULL);
+    assert_eq!(ab & bc, Item::B.into());
+    assert_eq!(ab & Item::C, Set::EMPTY);
+    assert_eq!(ab - bc, Item::A.into());
+    assert_eq!(ab - Item::A, Item::B.into());
+    assert_eq!(ab ^ bc, Set::from([Item::A, Item::C]));
+    assert_eq!(ab ^ Item::A, Item::B.into());
+    assert_eq!(!ab, Item::C.into());
+    assert_eq!(!Set::EMPTY, Set::FULL);
+}
+
+#[test]
+fn set_assign_ops() {
+    let mut set = Set::EMPTY;
+    set |= Item::A;
+    set |= [Item::B, Item::C];
+    assert_eq!(set, Set::FULL);
+    set &= [Item::A, Item::B];
+    assert_eq!(set, Set::from([Item::A, Item::B]));
+    set -= Item::A;
+    assert_eq!(set, Item::B.into());
+    set ^= [Item::B, Item::C];
+    assert_eq!(set, Item::C.into());
+}
+
+#[test]
+fn item_ops() {
+    assert_eq!(Item::A | Item::B, Set::from([Item::A, Item::B]));
+    assert_eq!(Item::A | Item::B | Item::C, Set::FULL);
+    assert_eq!(Item::A & Item::B, Set::EMPTY);
+    assert_eq!(Item::A & Set::FULL, Item::A.into());
+    assert_eq!(Item::A - Item::A, Set::EMPTY);
+    assert_eq!(Item::A ^ Set::FULL, Set::from([Item::B, Item::C]));
+    assert_eq!(!Item::A, Set::from([Item::B, Item::C]));
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 140)]
+        B,
+        C,
+    }
+
+    assert_eq!((Item::A | Item::B).len(), 2);
+    assert_eq!(!(Item::A | Item::B), Item::C.into());
+    assert_eq!((Item::A | Item::B) ^ (Item::B | Item::C), Item::A | Item::C);
+}
+
+#[test]
+fn retain_unknown_bits() {
+    #[flagnum::flag(Set, retain_unknown_bits)]
+    enum Item { A, B }
+
+    let set = Set::from_bits_retain(0b1001);
+    assert_eq!((!set).bits(), 0b1010);
+    assert_eq!((set ^ Item::A).bits(), 0b1000);
+}
@@ -0,0 +1,80 @@
+use flagnum::Flags;
+
+#[test]
+fn scalar() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 3)]
+        B,
+        #[flagnum(bit = 5)]
+        C,
+    }
+
+    let set = Set::from([Item::A, Item::C]);
+    let bits = 0b10_0001u8;
+    assert_eq!(format!("{set:b}"), format!("{bits:b}"));
+    assert_eq!(format!("{set:o}"), format!("{bits:o}"));
+    assert_eq!(format!("{set:x}"), format!("{bits:x}"));
+    assert_eq!(format!("{set:X}"), format!("{bits:X}"));
+    assert_eq!(format!("{set:08b}"), "00100001");
+    assert_eq!(format!("{set:>6x}"), "    21");
+    assert_eq!(format!("{:b}", Set::EMPTY), "0");
+
+    assert_eq!(format!("{set:#b}"), "0b00100001");
+    assert_eq!(format!("{set:#o}"), "0o041");
+    assert_eq!(format!("{set:#x}"), "0x21");
+    assert_eq!(format!("{:#X}", Set::from(Item::B)), "0x08");
+    assert_eq!(format!("{:#x}", Set::EMPTY), "0x00");
+}
+
+#[test]
+fn repr_width() {
+    #[flagnum::flag(Set, repr = u32)]
+    enum Item { A, B, C, D, E }
+
+    let set = Set::from([Item::B, Item::E]);
+    assert_eq!(format!("{set:x}"), "12");
+    assert_eq!(format!("{set:#x}"), "0x00000012");
+    assert_eq!(format!("{set:#o}"), "0o00000000022");
+    assert_eq!(format!("{set:#b}"), format!("{:#034b}", 0x12u32));
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 68)]
+        B,
+        #[flagnum(bit = 191)]
+        C,
+    }
+
+    let low = Set::from([Item::A, Item::B]);
+    assert_eq!(format!("{low:x}"), "100000000000000001");
+    assert_eq!(format!("{low:X}"), "100000000000000001");
+    assert_eq!(format!("{low:b}"), format!("{:b}", 1u128 << 68 | 1));
+    assert_eq!(format!("{low:o}"), format!("{:o}", 1u128 << 68 | 1));
+
+    let high = format!("{:#x}", Set::from(Item::C));
+    assert_eq!(high, format!("0x8{}", "0".repeat(47)));
+    assert_eq!(format!("{:#x}", Set::EMPTY), format!("0x{}", "0".repeat(48)));
+    assert_eq!(format!("{:#o}", Set::EMPTY).len(), 2 + 64);
+}
+
+#[test]
+fn padding() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C, D, E, F }
+
+    let set = Set::from([Item::A, Item::F]);
+    let bits = 0b10_0001u8;
+    assert_eq!(format!("{set:<6x}|"), format!("{bits:<6x}|"));
+    assert_eq!(format!("{set:^7b}|"), format!("{bits:^7b}|"));
+    assert_eq!(format!("{set:*>8o}"), format!("{bits:*>8o}"));
+    assert_eq!(format!("{set:#012b}"), format!("{bits:#012b}"));
+    assert_eq!(format!("{set:#06X}"), format!("{bits:#06X}"));
+    assert_eq!(format!("{set:-^#8x}"), "--0x21--");
+    assert_eq!(format!("{set:2b}"), "100001");
+}
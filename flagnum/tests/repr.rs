@@ -0,0 +1,35 @@
+use std::mem::size_of;
+
+use flagnum::Flags;
+
+#[test]
+fn automatic() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    assert_eq!(size_of::<Set>(), 1);
+    assert_eq!(size_of::<Item>(), 1);
+}
+
+#[test]
+fn explicit() {
+    #[flagnum::flag(Set8, repr = u8)]
+    enum Item8 { A, B, C, D, E, F, G, H }
+
+    #[flagnum::flag(Set32, repr = u32)]
+    enum Item32 { A, B, C }
+
+    #[flagnum::flag(Set128, repr = u128)]
+    enum Item128 { A }
+
+    assert_eq!(size_of::<Set8>(), 1);
+    assert_eq!(size_of::<Item8>(), 1);
+    assert_eq!(size_of::<Set32>(), 4);
+    assert_eq!(size_of::<Item32>(), 4);
+    assert_eq!(size_of::<Set128>(), 16);
+    assert_eq!(size_of::<Item128>(), 16);
+
+    assert_eq!(Set8::FULL.len(), 8);
+    assert_eq!(Set32::FULL.without(Item32::B), Set32::from([Item32::A, Item32::C]));
+    assert!(Set128::from_item(Item128::A).is_full());
+}
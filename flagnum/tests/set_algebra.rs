@@ -0,0 +1,81 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Set)]
+enum Item { A, B, C }
+
+#[test]
+fn subsets() {
+    let ab = Set::from([Item::A, Item::B]);
+
+    assert!(ab.is_subset_of(ab));
+    assert!(ab.is_subset_of(Set::FULL));
+    assert!(! ab.is_subset_of(Item::A));
+    assert!(Set::EMPTY.is_subset_of(Set::EMPTY));
+
+    assert!(ab.is_strict_subset_of(Set::FULL));
+    assert!(! ab.is_strict_subset_of(ab));
+    assert!(! ab.is_strict_subset_of([Item::A, Item::C]));
+
+    assert!(ab.is_superset_of(Item::A));
+    assert!(ab.is_superset_of(ab));
+    assert!(! ab.is_superset_of(Set::FULL));
+}
+
+#[test]
+fn disjoint() {
+    let ab = Set::from([Item::A, Item::B]);
+
+    assert!(ab.is_disjoint(Item::C));
+    assert!(ab.is_disjoint(Set::EMPTY));
+    assert!(! ab.is_disjoint([Item::B, Item::C]));
+}
+
+#[test]
+fn symmetric_difference() {
+    let ab = Set::from([Item::A, Item::B]);
+
+    assert_eq!(ab.symmetric_difference([Item::B, Item::C]), Set::from([Item::A, Item::C]));
+    assert_eq!(ab.symmetric_difference(ab), Set::EMPTY);
+
+    let mut set = ab;
+    set.toggle(Item::C);
+    assert!(set.is_full());
+    set.toggle(Set::FULL);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn const_fns() {
+    const AB: Set = Set::from_items(&[Item::A, Item::B]);
+    const A: Set = Set::from_item(Item::A);
+    const C: Set = Set::from_item(Item::C);
+    const SUBSET: bool = A.const_is_subset_of(AB);
+    const STRICT_SUBSET: bool = AB.const_is_strict_subset_of(AB);
+    const SUPERSET: bool = AB.const_is_superset_of(A);
+    const DISJOINT: bool = AB.const_is_disjoint(C);
+    const DIFFERENCE: Set = AB.const_symmetric_difference(Set::FULL);
+    const TOGGLED: Set = {
+        let mut set = AB;
+        set.const_toggle(C);
+        set
+    };
+
+    assert_eq!([SUBSET, STRICT_SUBSET, SUPERSET, DISJOINT], [true, false, true, true]);
+    assert_eq!(DIFFERENCE, C);
+    assert_eq!(TOGGLED, Set::FULL);
+}
+
+#[test]
+fn words() {
+    #[flagnum::flag(Set)]
+    enum Item {
+        A,
+        #[flagnum(bit = 140)]
+        B,
+        C,
+    }
+
+    assert!(Set::from(Item::B).is_strict_subset_of(Set::FULL));
+    assert!(Set::from(Item::B).is_disjoint([Item::A, Item::C]));
+    assert_eq!(Set::FULL.symmetric_difference(Item::B), Set::from([Item::A, Item::C]));
+}
@@ -0,0 +1,76 @@
+use flagnum::{Flags, ParseSetError};
+
+#[flagnum::flag(Set, groups(LOW, HIGH))]
+enum Item {
+    #[groups(LOW)]
+    A,
+    #[groups(LOW)]
+    B,
+    #[groups(HIGH)]
+    C,
+}
+
+#[test]
+fn display() {
+    assert_eq!(Set::EMPTY.to_string(), "");
+    assert_eq!(Set::from(Item::B).to_string(), "B");
+    assert_eq!(Set::FULL.to_string(), "A|B|C");
+    assert_eq!(Set::from([Item::C, Item::A]).to_string(), "A|C");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("".parse(), Ok(Set::EMPTY));
+    assert_eq!("  ".parse(), Ok(Set::EMPTY));
+    assert_eq!("A".parse(), Ok(Set::from(Item::A)));
+    assert_eq!("C|A".parse(), Ok(Set::from([Item::A, Item::C])));
+    assert_eq!(" A | C ".parse(), Ok(Set::from([Item::A, Item::C])));
+    assert_eq!("A|A".parse(), Ok(Set::from(Item::A)));
+    assert_eq!("LOW".parse(), Ok(Set::LOW));
+    assert_eq!("HIGH|B".parse(), Ok(Set::from([Item::B, Item::C])));
+}
+
+#[test]
+fn roundtrip() {
+    for bits in 0..=Set::FULL.bits() {
+        let set = Set::from_bits(bits).unwrap();
+        assert_eq!(set.to_string().parse(), Ok(set));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!("A|D".parse::<Set>(), Err(ParseSetError::new("D", 2)));
+    assert_eq!("A| D |B".parse::<Set>(), Err(ParseSetError::new("D", 3)));
+    assert_eq!("A||B".parse::<Set>(), Err(ParseSetError::new("", 2)));
+    assert_eq!("a".parse::<Set>(), Err(ParseSetError::new("a", 0)));
+    assert_eq!("Low".parse::<Set>(), Err(ParseSetError::new("Low", 0)));
+
+    let error = "A|B|X".parse::<Set>().unwrap_err();
+    assert_eq!(error.offset(), 4);
+    assert_eq!(error.range(), 4..5);
+    assert_eq!(error.token("A|B|X"), Some("X"));
+    assert_eq!(error.token("A"), None);
+    assert_eq!(error.to_string(), "unknown set element at byte offset 4");
+}
+
+#[test]
+fn separator() {
+    #[flagnum::flag(Words, separator = " + ", rename_all = "kebab-case")]
+    enum Word { FirstWord, SecondWord }
+
+    let words = Words::from([Word::FirstWord, Word::SecondWord]);
+    assert_eq!(words.to_string(), "first-word + second-word");
+    assert_eq!("second-word + first-word".parse(), Ok(words));
+    assert_eq!("first-word|second-word".parse::<Words>().unwrap_err().offset(), 0);
+}
+
+#[test]
+fn group_named_like_item() {
+    #[flagnum::flag(Days, groups(MONDAY = Monday | Tuesday), rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Day { Monday, Tuesday }
+
+    assert_eq!(Days::MONDAY, Days::FULL);
+    assert_eq!("MONDAY".parse(), Ok(Days::from(Day::Monday)));
+    assert_eq!("MONDAY|TUESDAY".parse(), Ok(Days::FULL));
+}
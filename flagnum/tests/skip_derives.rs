@@ -0,0 +1,136 @@
+// The manual `PartialEq` impls below agree with the derived `Hash` impls.
+#![allow(clippy::derived_hash_with_manual_eq)]
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use flagnum::Flags;
+
+#[flagnum::flag(Set, skip_item_derives(Debug, PartialOrd, Ord, Display))]
+enum Item { A, B, C }
+
+impl fmt::Debug for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::A => "a",
+            Self::B => "b",
+            Self::C => "c",
+        })
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {self:?}")
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self as u8).cmp(&(*other as u8)).reverse()
+    }
+}
+
+#[test]
+fn item_impls() {
+    assert_eq!(format!("{:?}", Item::B), "b");
+    assert_eq!(Item::C.to_string(), "item c");
+    assert_eq!(Item::A.cmp(&Item::C), Ordering::Greater);
+    assert_eq!(format!("{:?}", Set::FULL), "{a, b, c}");
+    assert_eq!("A".parse(), Ok(Item::A));
+}
+
+#[flagnum::flag(Custom, skip_set_derives(Debug, Display, Binary, Hash))]
+enum CustomItem { X, Y }
+
+impl fmt::Debug for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Custom({} items)", self.len())
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.bits())
+    }
+}
+
+impl fmt::Binary for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bits")
+    }
+}
+
+impl std::hash::Hash for Custom {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+#[test]
+fn set_impls() {
+    let set = Custom::from(CustomItem::Y);
+    assert_eq!(format!("{set:?}"), "Custom(1 items)");
+    assert_eq!(set.to_string(), "2");
+    assert_eq!(format!("{set:b}"), "bits");
+    assert_eq!(format!("{set:x}"), "2");
+    assert_eq!("X|Y".parse(), Ok(Custom::FULL));
+}
+
+#[flagnum::flag(Pinned, skip_item_derives(PartialEq, Clone), skip_set_derives(PartialEq))]
+enum PinnedItem {
+    #[flagnum(bit = 2)]
+    X,
+    Y,
+    #[flagnum(bit = 0)]
+    Z,
+}
+
+impl PartialEq for PinnedItem {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl Clone for PinnedItem {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for Pinned {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+#[test]
+fn dependent_impls() {
+    assert_eq!(PinnedItem::X, PinnedItem::X.clone());
+    assert_eq!(PinnedItem::X.cmp(&PinnedItem::Y), Ordering::Less);
+    assert_eq!(PinnedItem::Z.partial_cmp(&PinnedItem::X), Some(Ordering::Less));
+    assert_eq!(Pinned::from(PinnedItem::X), PinnedItem::X.into());
+    assert!(Pinned::from(PinnedItem::Z) < Pinned::from(PinnedItem::X));
+}
+
+#[test]
+fn dependent_impls_keep_discriminants() {
+    #[flagnum::flag(Set, keep_discriminants, skip_item_derives(PartialEq))]
+    enum Item { A = 5, B = -1, C = 0 }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            *self as i8 == *other as i8
+        }
+    }
+
+    let mut items = vec![Item::A, Item::B, Item::C];
+    items.sort();
+    assert_eq!(items, vec![Item::B, Item::C, Item::A]);
+}
@@ -31,7 +31,9 @@ fn from() {
     assert_eq!(Set::from([Item::A]), set_with_a);
     assert_eq!(Set::from(&[Item::A]), set_with_a);
     assert_eq!(Set::from(&[Item::A][..]), set_with_a);
+    #[cfg(feature = "alloc")]
     assert_eq!(Set::from(Vec::from([Item::A])), set_with_a);
+    #[cfg(feature = "alloc")]
     assert_eq!(Set::from(&Vec::from([Item::A])), set_with_a);
 }
 
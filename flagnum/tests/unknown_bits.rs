@@ -0,0 +1,48 @@
+use flagnum::Flags;
+
+#[flagnum::flag(Set, retain_unknown_bits)]
+enum Item { A, B, C }
+
+#[test]
+fn construct() {
+    assert_eq!(Set::from_bits_retain(0b1111_0001).bits(), 0b1111_0001);
+    assert_eq!(Set::from_bits_truncate(0b1111_0001).bits(), 0b0000_0001);
+    assert_eq!(Set::from_bits(0b1111_0001).unwrap_err().bits(), 0b1111_0000);
+}
+
+#[test]
+fn known_items() {
+    let set = Set::from_bits_retain(0b1000_0011);
+    assert_eq!(set.len(), 2);
+    assert!(! set.is_empty());
+    assert!(Set::from_bits_retain(0b1000_0000).is_empty());
+    assert!(Set::from_bits_retain(0b1000_0111).is_full());
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), Vec::from([Item::A, Item::B]));
+}
+
+#[test]
+fn read_modify_write() {
+    let mut set = Set::from_bits_retain(0b1000_0011);
+    set.remove(Item::A);
+    set.insert(Item::C);
+    assert_eq!(set.bits(), 0b1000_0110);
+    assert_eq!(set.missing().bits(), 0b1000_0001);
+    set.invert();
+    assert_eq!(set.bits(), 0b1000_0001);
+    set.retain(|_| false);
+    assert_eq!(set.bits(), 0b1000_0000);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", Set::from_bits_retain(0b0100_0010)), "{B, UnknownBits { bits: 64 }}");
+    assert_eq!(format!("{:?}", Set::from(Item::B)), "{B}");
+}
+
+#[test]
+fn not_retained() {
+    #[flagnum::flag(Set)]
+    enum Item { A, B, C }
+
+    assert_eq!(Set::from_bits_retain(0b1000_0011), Set::from([Item::A, Item::B]));
+}